@@ -0,0 +1,324 @@
+//! 从上游ISO 3166 CSV和CLDR区域名称文件重新生成 `resources/countries.json`
+//!
+//! ```text
+//! cargo run --example regenerate_dataset -- \
+//!     --iso iso3166.csv \
+//!     --cldr en=cldr-json/cldr-localenames-full/main/en/territories.json \
+//!     --cldr zh=cldr-json/cldr-localenames-full/main/zh/territories.json \
+//!     --cldr zh-Hant=cldr-json/cldr-localenames-full/main/zh-Hant/territories.json \
+//!     --cldr ja=cldr-json/cldr-localenames-full/main/ja/territories.json \
+//!     --write
+//! ```
+//!
+//! ISO文件需要包含表头，识别 `alpha2`、`alpha3`、`numeric` 和 `name` 列（忽略大小写和标点，
+//! 如 "Alpha-2 code"）。代码和名称取自上游文件，别称、地区、区号等手工维护的字段保持不变，
+//! 名称变化时旧名称保留为别称。默认只打印差异，加 `--write` 才写回文件。
+
+use std::collections::BTreeMap;
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use location_rs::Configuration;
+
+const DEFAULT_COUNTRIES_PATH: &str = "resources/countries.json";
+
+/// 国家数据集，字段顺序与 `countries.json` 一致
+#[derive(Debug, Serialize, Deserialize)]
+struct Dataset {
+    version: String,
+    countries: Vec<Country>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Country {
+    alpha2: String,
+    alpha3: String,
+    numeric: Option<String>,
+    name_en: String,
+    name_zh_cn: String,
+    name_zh_tw: String,
+    region: Option<String>,
+    sub_region: Option<String>,
+    intermediate_region: Option<String>,
+    sovereign: Option<String>,
+    calling_codes: Vec<String>,
+    names: BTreeMap<String, Vec<String>>,
+    abbreviations: Vec<String>,
+}
+
+impl Country {
+    fn new(alpha2: &str) -> Self {
+        Self {
+            alpha2: alpha2.to_string(),
+            alpha3: String::new(),
+            numeric: None,
+            name_en: String::new(),
+            name_zh_cn: String::new(),
+            name_zh_tw: String::new(),
+            region: None,
+            sub_region: None,
+            intermediate_region: None,
+            sovereign: None,
+            calling_codes: Vec::new(),
+            names: BTreeMap::new(),
+            abbreviations: Vec::new(),
+        }
+    }
+
+    /// 更新名称，原名称不为空时保留为别称
+    fn rename(&mut self, field: fn(&mut Country) -> &mut String, name: &str) {
+        let old = std::mem::replace(field(self), name.to_string());
+        if !old.is_empty() && old != name && !self.abbreviations.contains(&old) {
+            self.abbreviations.push(old);
+        }
+    }
+
+    /// 更新其他语言的首选名称，原名称保留在列表中
+    fn rename_in(&mut self, locale: &str, name: &str) {
+        let names = self.names.entry(locale.to_string()).or_default();
+        names.retain(|existing| existing != name);
+        names.insert(0, name.to_string());
+    }
+}
+
+/// ISO 3166 CSV中的一行
+struct IsoEntry {
+    alpha2: String,
+    alpha3: String,
+    numeric: Option<String>,
+    name: Option<String>,
+}
+
+struct Args {
+    iso: Option<String>,
+    cldr: Vec<(String, String)>,
+    countries: String,
+    write: bool,
+}
+
+fn parse_args() -> Result<Args> {
+    let mut args = Args {
+        iso: None,
+        cldr: Vec::new(),
+        countries: DEFAULT_COUNTRIES_PATH.to_string(),
+        write: false,
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().with_context(|| format!("{} 缺少参数", arg));
+        match arg.as_str() {
+            "--iso" => args.iso = Some(value()?),
+            "--cldr" => {
+                let value = value()?;
+                let (locale, path) = value.split_once('=').context("--cldr 的格式应为 <语言>=<文件>")?;
+                args.cldr.push((locale.to_string(), path.to_string()));
+            }
+            "--countries" => args.countries = value()?,
+            "--write" => args.write = true,
+            _ => bail!("未知参数: {}", arg),
+        }
+    }
+
+    if args.iso.is_none() && args.cldr.is_empty() {
+        bail!("至少需要提供 --iso 或 --cldr");
+    }
+    Ok(args)
+}
+
+fn main() -> Result<()> {
+    let args = parse_args()?;
+
+    let original = fs::read_to_string(&args.countries).with_context(|| format!("无法读取 {}", args.countries))?;
+    let mut dataset: Dataset = serde_json::from_str(&original).context("国家配置解析失败")?;
+    let before = dataset.countries.clone();
+    let mut notes = Vec::new();
+
+    if let Some(path) = &args.iso {
+        let entries = read_iso_csv(path)?;
+        apply_iso(&mut dataset, &entries, &mut notes);
+    }
+    for (locale, path) in &args.cldr {
+        let names = read_cldr_territories(path)?;
+        apply_cldr(&mut dataset, locale, &names)?;
+    }
+
+    let changes = diff(&before, &dataset.countries);
+    for line in notes.iter().chain(&changes) {
+        println!("{}", line);
+    }
+    if changes.is_empty() {
+        println!("没有变化");
+        return Ok(());
+    }
+
+    let output = serde_json::to_string_pretty(&dataset)?;
+    // 只显示错误和变化国家的警告，已有数据中的冗余别名等警告不重复输出
+    let report = Configuration::from_countries_json(&output)?.validate();
+    let find = |countries: &'_ [Country], alpha2: &str| countries.iter().find(|c| c.alpha2 == alpha2).cloned();
+    let changed = |alpha2: &str| find(&before, alpha2) != find(&dataset.countries, alpha2);
+    let warnings = report.warnings.iter().filter(|issue| changed(&issue.alpha2));
+    for issue in report.errors.iter().chain(warnings) {
+        println!("校验: {}", issue);
+    }
+
+    if args.write {
+        fs::write(&args.countries, output).with_context(|| format!("无法写入 {}", args.countries))?;
+        println!("已写入 {}（{} 处变化）", args.countries, changes.len());
+    } else {
+        println!("共 {} 处变化，使用 --write 写回文件", changes.len());
+    }
+    Ok(())
+}
+
+/// 读取ISO 3166 CSV
+fn read_iso_csv(path: &str) -> Result<Vec<IsoEntry>> {
+    let text = fs::read_to_string(path).with_context(|| format!("无法读取 {}", path))?;
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+
+    let header = split_csv_line(lines.next().context("ISO文件为空")?);
+    let column = |names: &[&str]| {
+        header.iter().position(|field| {
+            let key: String = field.chars().filter(char::is_ascii_alphanumeric).collect();
+            names.contains(&key.to_lowercase().as_str())
+        })
+    };
+    let alpha2 = column(&["alpha2", "alpha2code"]).context("ISO文件缺少alpha2列")?;
+    let alpha3 = column(&["alpha3", "alpha3code"]).context("ISO文件缺少alpha3列")?;
+    let numeric = column(&["numeric", "numericcode"]);
+    let name = column(&["name", "englishshortname", "countryname"]);
+
+    let mut entries = Vec::new();
+    for line in lines {
+        let fields = split_csv_line(line);
+        let field = |i: usize| fields.get(i).map(|f| f.trim()).filter(|f| !f.is_empty());
+        let (Some(a2), Some(a3)) = (field(alpha2), field(alpha3)) else {
+            bail!("ISO文件中的行缺少代码: {}", line);
+        };
+        entries.push(IsoEntry {
+            alpha2: a2.to_uppercase(),
+            alpha3: a3.to_uppercase(),
+            numeric: numeric.and_then(field).map(|n| format!("{:0>3}", n)),
+            name: name.and_then(field).map(str::to_string),
+        });
+    }
+    Ok(entries)
+}
+
+/// 拆分CSV行，支持双引号包裹和 `""` 转义
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            _ => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+/// 读取CLDR JSON中的区域名称，忽略 "-alt-" 变体
+fn read_cldr_territories(path: &str) -> Result<BTreeMap<String, String>> {
+    let text = fs::read_to_string(path).with_context(|| format!("无法读取 {}", path))?;
+    let value: Value = serde_json::from_str(&text).with_context(|| format!("{} 不是有效的JSON", path))?;
+
+    let territories = find_key(&value, "territories")
+        .and_then(Value::as_object)
+        .with_context(|| format!("{} 中没有 territories", path))?;
+
+    Ok(territories
+        .iter()
+        .filter(|(code, _)| code.len() == 2 && !code.contains("-alt-"))
+        .filter_map(|(code, name)| Some((code.clone(), name.as_str()?.to_string())))
+        .collect())
+}
+
+fn find_key<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    let object = value.as_object()?;
+    object.get(key).or_else(|| object.values().find_map(|child| find_key(child, key)))
+}
+
+/// 使用ISO文件更新代码，新增上游新出现的国家
+fn apply_iso(dataset: &mut Dataset, entries: &[IsoEntry], notes: &mut Vec<String>) {
+    for entry in entries {
+        let position = dataset.countries.iter().position(|c| c.alpha2 == entry.alpha2);
+        let country = match position {
+            Some(i) => &mut dataset.countries[i],
+            None => {
+                dataset.countries.push(Country::new(&entry.alpha2));
+                dataset.countries.last_mut().unwrap()
+            }
+        };
+
+        country.alpha3 = entry.alpha3.clone();
+        if entry.numeric.is_some() {
+            country.numeric = entry.numeric.clone();
+        }
+        // ISO英文名称（如 "Korea, Republic of"）只用于新增的国家，已有国家以CLDR名称为准
+        if let (None, Some(name)) = (position, &entry.name) {
+            country.name_en = name.clone();
+        }
+    }
+
+    for country in &dataset.countries {
+        if !entries.iter().any(|entry| entry.alpha2 == country.alpha2) {
+            notes.push(format!("? {} {} 不在ISO文件中，保持不变", country.alpha2, country.name_en));
+        }
+    }
+}
+
+/// 使用CLDR区域名称更新对应语言的名称
+fn apply_cldr(dataset: &mut Dataset, locale: &str, names: &BTreeMap<String, String>) -> Result<()> {
+    let field: Option<fn(&mut Country) -> &mut String> = match locale {
+        "en" => Some(|c| &mut c.name_en),
+        "zh" | "zh-Hans" | "zh-CN" => Some(|c| &mut c.name_zh_cn),
+        "zh-Hant" | "zh-TW" => Some(|c| &mut c.name_zh_tw),
+        _ if locale.starts_with("zh") => bail!("不支持的中文区域: {}", locale),
+        _ => None,
+    };
+
+    for country in &mut dataset.countries {
+        let Some(name) = names.get(&country.alpha2) else {
+            continue;
+        };
+        match field {
+            Some(field) => country.rename(field, name),
+            None => country.rename_in(locale, name),
+        }
+    }
+    Ok(())
+}
+
+/// 逐字段比较新旧数据集
+fn diff(before: &[Country], after: &[Country]) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    for country in after {
+        let Some(old) = before.iter().find(|old| old.alpha2 == country.alpha2) else {
+            changes.push(format!("+ {} {}", country.alpha2, country.name_en));
+            continue;
+        };
+        if old == country {
+            continue;
+        }
+
+        let (old_value, new_value) = (serde_json::to_value(old).unwrap(), serde_json::to_value(country).unwrap());
+        for (key, value) in new_value.as_object().unwrap() {
+            if old_value[key] != *value {
+                changes.push(format!("~ {} {}: {} -> {}", country.alpha2, key, old_value[key], value));
+            }
+        }
+    }
+    changes
+}
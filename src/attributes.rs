@@ -0,0 +1,407 @@
+//! 节点属性
+//!
+//! 从节点名称中提取国家以外的属性：流量倍率（"0.5x"、"×2"、"倍率1.5"）、线路类型（"IPLC"、"CN2 GIA"、"家宽"）、
+//! 运营商（"中国移动"、"China Telecom"、"CN2"）、带宽（"1Gbps"、"100M"）和流媒体标签（"Netflix"、"解锁"）。
+//! 每个属性都带有在原文中的字节范围。
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::parser::{is_word_bounded, FoldedText};
+
+/// 带有原文位置的属性值
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribute<T> {
+    /// 属性值
+    pub value: T,
+    /// 命中文本在原文中的字节范围
+    pub span: Range<usize>,
+}
+
+/// 线路类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineType {
+    /// 国际私有专线
+    Iplc,
+    /// 国际以太网专线
+    Iepl,
+    /// BGP多线
+    Bgp,
+    /// 中国电信CN2 GIA
+    Cn2Gia,
+    /// 中国电信CN2 GT
+    Cn2Gt,
+    /// 未注明等级的CN2
+    Cn2,
+    /// 家庭宽带（"家宽"、"住宅"）
+    Residential,
+    /// 原生IP
+    NativeIp,
+}
+
+impl LineType {
+    /// 线路类型名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Iplc => "IPLC",
+            Self::Iepl => "IEPL",
+            Self::Bgp => "BGP",
+            Self::Cn2Gia => "CN2 GIA",
+            Self::Cn2Gt => "CN2 GT",
+            Self::Cn2 => "CN2",
+            Self::Residential => "Residential",
+            Self::NativeIp => "Native IP",
+        }
+    }
+}
+
+impl fmt::Display for LineType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 中国大陆运营商
+///
+/// 解析国家时会先找出运营商名称，其中的 "中国"、"CN" 等不再被识别为国家。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Carrier {
+    /// 中国电信（含CN2网络）
+    ChinaTelecom,
+    /// 中国联通（含原中国网通CNC，以及AS9929、AS4837网络）
+    ChinaUnicom,
+    /// 中国移动（含CMI网络和中国移动香港CMHK）
+    ChinaMobile,
+    /// 中国广电
+    ChinaBroadnet,
+}
+
+impl Carrier {
+    /// 运营商名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ChinaTelecom => "China Telecom",
+            Self::ChinaUnicom => "China Unicom",
+            Self::ChinaMobile => "China Mobile",
+            Self::ChinaBroadnet => "China Broadnet",
+        }
+    }
+}
+
+impl fmt::Display for Carrier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 流媒体标签
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamingTag {
+    Netflix,
+    DisneyPlus,
+    Hbo,
+    Hulu,
+    PrimeVideo,
+    YouTube,
+    ChatGpt,
+    TikTok,
+    Bilibili,
+    /// 未注明服务的解锁标记（"解锁"、"流媒体"、"Unlock"）
+    Unlock,
+}
+
+impl StreamingTag {
+    /// 服务名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Netflix => "Netflix",
+            Self::DisneyPlus => "Disney+",
+            Self::Hbo => "HBO",
+            Self::Hulu => "Hulu",
+            Self::PrimeVideo => "Prime Video",
+            Self::YouTube => "YouTube",
+            Self::ChatGpt => "ChatGPT",
+            Self::TikTok => "TikTok",
+            Self::Bilibili => "bilibili",
+            Self::Unlock => "Unlock",
+        }
+    }
+}
+
+impl fmt::Display for StreamingTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 节点名称中的属性
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeAttributes {
+    /// 流量倍率
+    pub multiplier: Option<Attribute<f64>>,
+    /// 带宽，单位为Mbps（"1Gbps" 为1000）
+    pub bandwidth: Option<Attribute<f64>>,
+    /// 线路类型，按出现顺序排列
+    pub line_types: Vec<Attribute<LineType>>,
+    /// 运营商，按出现顺序排列
+    pub carriers: Vec<Attribute<Carrier>>,
+    /// 流媒体标签，按出现顺序排列，同一标签只保留第一次出现
+    pub streaming: Vec<Attribute<StreamingTag>>,
+}
+
+impl NodeAttributes {
+    /// 是否没有任何属性
+    pub fn is_empty(&self) -> bool {
+        self.multiplier.is_none()
+            && self.bandwidth.is_none()
+            && self.line_types.is_empty()
+            && self.carriers.is_empty()
+            && self.streaming.is_empty()
+    }
+}
+
+/// 线路类型词条（小写），ASCII词条需要完整单词匹配
+const LINE_TYPE_TERMS: &[(&str, LineType)] = &[
+    ("cn2 gia", LineType::Cn2Gia),
+    ("cn2-gia", LineType::Cn2Gia),
+    ("cn2gia", LineType::Cn2Gia),
+    ("cn2 gt", LineType::Cn2Gt),
+    ("cn2-gt", LineType::Cn2Gt),
+    ("cn2gt", LineType::Cn2Gt),
+    ("cn2", LineType::Cn2),
+    ("iplc", LineType::Iplc),
+    ("iepl", LineType::Iepl),
+    ("bgp", LineType::Bgp),
+    ("家庭宽带", LineType::Residential),
+    ("家宽", LineType::Residential),
+    ("住宅", LineType::Residential),
+    ("residential", LineType::Residential),
+    ("原生ip", LineType::NativeIp),
+    ("原生", LineType::NativeIp),
+    ("native ip", LineType::NativeIp),
+];
+
+/// 运营商词条（小写），ASCII词条需要完整单词匹配
+const CARRIER_TERMS: &[(&str, Carrier)] = &[
+    ("中国电信", Carrier::ChinaTelecom),
+    ("中國電信", Carrier::ChinaTelecom),
+    ("电信", Carrier::ChinaTelecom),
+    ("電信", Carrier::ChinaTelecom),
+    ("china telecom", Carrier::ChinaTelecom),
+    ("chinatelecom", Carrier::ChinaTelecom),
+    ("ctcc", Carrier::ChinaTelecom),
+    ("cn2 gia", Carrier::ChinaTelecom),
+    ("cn2-gia", Carrier::ChinaTelecom),
+    ("cn2gia", Carrier::ChinaTelecom),
+    ("cn2 gt", Carrier::ChinaTelecom),
+    ("cn2-gt", Carrier::ChinaTelecom),
+    ("cn2gt", Carrier::ChinaTelecom),
+    ("cn2", Carrier::ChinaTelecom),
+    ("中国联通", Carrier::ChinaUnicom),
+    ("中國聯通", Carrier::ChinaUnicom),
+    ("联通", Carrier::ChinaUnicom),
+    ("聯通", Carrier::ChinaUnicom),
+    ("china unicom", Carrier::ChinaUnicom),
+    ("chinaunicom", Carrier::ChinaUnicom),
+    ("cucc", Carrier::ChinaUnicom),
+    ("cuii", Carrier::ChinaUnicom),
+    ("as9929", Carrier::ChinaUnicom),
+    ("as4837", Carrier::ChinaUnicom),
+    ("中国网通", Carrier::ChinaUnicom),
+    ("中國網通", Carrier::ChinaUnicom),
+    ("网通", Carrier::ChinaUnicom),
+    ("網通", Carrier::ChinaUnicom),
+    ("china netcom", Carrier::ChinaUnicom),
+    ("cnc", Carrier::ChinaUnicom),
+    ("中国移动", Carrier::ChinaMobile),
+    ("中國移動", Carrier::ChinaMobile),
+    ("移动", Carrier::ChinaMobile),
+    ("移動", Carrier::ChinaMobile),
+    ("china mobile", Carrier::ChinaMobile),
+    ("chinamobile", Carrier::ChinaMobile),
+    ("cmcc", Carrier::ChinaMobile),
+    ("cmin2", Carrier::ChinaMobile),
+    ("cmi", Carrier::ChinaMobile),
+    ("cmhk", Carrier::ChinaMobile),
+    ("中国广电", Carrier::ChinaBroadnet),
+    ("中國廣電", Carrier::ChinaBroadnet),
+    ("广电", Carrier::ChinaBroadnet),
+    ("廣電", Carrier::ChinaBroadnet),
+];
+
+/// 流媒体词条（小写），ASCII词条需要完整单词匹配
+const STREAMING_TERMS: &[(&str, StreamingTag)] = &[
+    ("netflix", StreamingTag::Netflix),
+    ("奈飞", StreamingTag::Netflix),
+    ("网飞", StreamingTag::Netflix),
+    ("disney+", StreamingTag::DisneyPlus),
+    ("disney", StreamingTag::DisneyPlus),
+    ("迪士尼", StreamingTag::DisneyPlus),
+    ("hbo", StreamingTag::Hbo),
+    ("hulu", StreamingTag::Hulu),
+    ("prime video", StreamingTag::PrimeVideo),
+    ("primevideo", StreamingTag::PrimeVideo),
+    ("youtube", StreamingTag::YouTube),
+    ("油管", StreamingTag::YouTube),
+    ("chatgpt", StreamingTag::ChatGpt),
+    ("openai", StreamingTag::ChatGpt),
+    ("tiktok", StreamingTag::TikTok),
+    ("bilibili", StreamingTag::Bilibili),
+    ("b站", StreamingTag::Bilibili),
+    ("解锁", StreamingTag::Unlock),
+    ("流媒体", StreamingTag::Unlock),
+    ("unlock", StreamingTag::Unlock),
+];
+
+/// 倍率符号
+const MULTIPLIER_SIGNS: &[char] = &['x', 'X', '×'];
+
+/// 提取节点名称中的属性
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::attributes::{extract_attributes, LineType, StreamingTag};
+///
+/// let attributes = extract_attributes("美国 CN2 GIA 0.5x 1Gbps Netflix");
+/// assert_eq!(attributes.multiplier.unwrap().value, 0.5);
+/// assert_eq!(attributes.bandwidth.unwrap().value, 1000.0);
+/// assert_eq!(attributes.line_types[0].value, LineType::Cn2Gia);
+/// assert_eq!(attributes.line_types[0].span, 7..14);
+/// assert_eq!(attributes.streaming[0].value, StreamingTag::Netflix);
+/// ```
+pub fn extract_attributes(text: &str) -> NodeAttributes {
+    let folded = FoldedText::new(text);
+
+    NodeAttributes {
+        multiplier: numbers(text).find_map(|(value, start, end)| multiplier_at(text, value, start, end)),
+        bandwidth: numbers(text).find_map(|(value, start, end)| bandwidth_at(text, value, start, end)),
+        line_types: find_terms(text, &folded, LINE_TYPE_TERMS),
+        carriers: find_terms(text, &folded, CARRIER_TERMS),
+        streaming: find_terms(text, &folded, STREAMING_TERMS),
+    }
+}
+
+/// 查找文本中所有运营商名称的出现位置，按出现顺序排列
+pub(crate) fn find_carriers(text: &str, folded: &FoldedText) -> Vec<Attribute<Carrier>> {
+    find_all_terms(text, folded, CARRIER_TERMS)
+}
+
+/// 按最长匹配查找词条，结果按出现顺序排列且不重复
+fn find_terms<T: Copy + PartialEq>(text: &str, folded: &FoldedText, terms: &[(&str, T)]) -> Vec<Attribute<T>> {
+    let mut unique: Vec<Attribute<T>> = Vec::new();
+    for attribute in find_all_terms(text, folded, terms) {
+        if !unique.iter().any(|a| a.value == attribute.value) {
+            unique.push(attribute);
+        }
+    }
+    unique
+}
+
+/// 按最长匹配查找词条的所有出现位置，已匹配的范围不再重复匹配，结果按出现顺序排列
+fn find_all_terms<T: Copy>(text: &str, folded: &FoldedText, terms: &[(&str, T)]) -> Vec<Attribute<T>> {
+    let mut sorted: Vec<&(&str, T)> = terms.iter().collect();
+    sorted.sort_by_key(|(term, _)| std::cmp::Reverse(term.len()));
+
+    let mut found: Vec<Attribute<T>> = Vec::new();
+    for &&(term, value) in &sorted {
+        for (start, end) in folded.find_all(term) {
+            let overlaps = found.iter().any(|a| a.span.start < end && start < a.span.end);
+            if overlaps || (term.is_ascii() && !is_word_bounded(text, start, end)) {
+                continue;
+            }
+            found.push(Attribute { value, span: start..end });
+        }
+    }
+
+    found.sort_by_key(|a| a.span.start);
+    found
+}
+
+/// 查找文本中的数字（如 "1.5"），返回数值和字节范围
+pub(crate) fn numbers(text: &str) -> impl Iterator<Item = (f64, usize, usize)> + '_ {
+    let bytes = text.as_bytes();
+
+    (0..bytes.len()).filter_map(move |start| {
+        if !bytes[start].is_ascii_digit() || (start > 0 && (bytes[start - 1].is_ascii_digit() || bytes[start - 1] == b'.')) {
+            return None;
+        }
+
+        let digits = |from: usize| bytes[from..].iter().take_while(|b| b.is_ascii_digit()).count();
+        let mut end = start + digits(start);
+        if bytes.get(end) == Some(&b'.') && bytes.get(end + 1).is_some_and(u8::is_ascii_digit) {
+            end += 1 + digits(end + 1);
+        }
+        Some((text[start..end].parse().ok()?, start, end))
+    })
+}
+
+/// 检查数字是否是倍率：前面或后面紧跟 "x"、"×"，后面跟 "倍"，或前面是 "倍率"
+fn multiplier_at(text: &str, value: f64, start: usize, end: usize) -> Option<Attribute<f64>> {
+    let before = &text[..start];
+    let after = &text[end..];
+    let prev = before.chars().next_back();
+    if prev.is_some_and(|c| c.is_ascii_alphabetic() && !MULTIPLIER_SIGNS.contains(&c)) {
+        return None;
+    }
+
+    let mut chars = after.chars();
+    let span = match (chars.next(), chars.next()) {
+        (Some(sign), next) if MULTIPLIER_SIGNS.contains(&sign) && !next.is_some_and(|c| c.is_ascii_alphanumeric()) => {
+            start..end + sign.len_utf8()
+        }
+        _ if after.starts_with("倍率") => start..end + "倍率".len(),
+        _ if after.starts_with('倍') => start..end + '倍'.len_utf8(),
+        _ => {
+            let sign_start = before.len() - prev?.len_utf8();
+            let keyword = before.trim_end_matches([' ', ':', '：']);
+            if MULTIPLIER_SIGNS.contains(&prev?) {
+                let outer = before[..sign_start].chars().next_back();
+                if outer.is_some_and(|c| c.is_ascii_alphanumeric()) {
+                    return None;
+                }
+                sign_start..end
+            } else if keyword.ends_with("倍率") {
+                keyword.len() - "倍率".len()..end
+            } else {
+                return None;
+            }
+        }
+    };
+
+    Some(Attribute { value, span })
+}
+
+/// 检查数字是否是带宽：后面跟着 "G"、"M"、"K" 单位，可以带 "bps"、"bit/s" 等后缀（"GB" 等流量单位和 "5G" 等网络代际除外）
+fn bandwidth_at(text: &str, value: f64, start: usize, end: usize) -> Option<Attribute<f64>> {
+    if text[..start].chars().next_back().is_some_and(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let rest = text[end..].strip_prefix(' ').unwrap_or(&text[end..]);
+    let unit = rest.chars().next()?;
+    let scale = match unit.to_ascii_uppercase() {
+        'G' => 1000.0,
+        'M' => 1.0,
+        'K' => 0.001,
+        _ => return None,
+    };
+
+    let suffix = &rest[1..];
+    let lower = suffix.to_ascii_lowercase();
+    let suffix_len = ["bit/s", "bps", "b/s", "bit"]
+        .iter()
+        .find(|s| lower.starts_with(*s))
+        .map_or(0, |s| s.len());
+    if suffix_len == 0 && suffix.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    // "4G"、"5G" 等单个数字通常是移动网络代际，需要写明 "Gbps" 或 "G带宽"
+    let generation = text[start..end].len() == 1 && unit.eq_ignore_ascii_case(&'G');
+    if generation && suffix_len == 0 && !suffix.starts_with("带宽") {
+        return None;
+    }
+
+    let span_end = text.len() - suffix.len() + suffix_len;
+    Some(Attribute { value: value * scale, span: start..span_end })
+}
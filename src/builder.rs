@@ -0,0 +1,149 @@
+//! 解析器构建器
+//!
+//! 在代码中追加或移除别名、边界模式以及匹配阶段，无需修改JSON配置。
+
+use crate::config::{Configuration, PatternKind};
+use crate::error::ParseError;
+use crate::parser::{CountryIndex, MatchStage};
+use crate::{Parser, ParserConfig};
+
+/// 别名修改操作，按添加顺序应用
+#[derive(Debug, Clone)]
+enum AliasEdit {
+    Add { alpha2: String, term: String },
+    Remove { alpha2: String, term: String },
+}
+
+/// 解析器构建器
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::Parser;
+///
+/// let parser = Parser::builder()
+///     .add_alias("SG", "狮城")
+///     .add_alias("JP", "霓虹")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(parser.parse("狮城 01").unwrap().alpha2, "SG");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParserBuilder {
+    configuration: Option<Configuration>,
+    config: ParserConfig,
+    alias_edits: Vec<AliasEdit>,
+    patterns: Vec<(PatternKind, String)>,
+}
+
+impl ParserBuilder {
+    /// 使用内置数据集和默认配置创建构建器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 使用自定义的数据集
+    pub fn configuration(mut self, configuration: Configuration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+
+    /// 使用自定义的解析器配置
+    pub fn config(mut self, config: ParserConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// 为国家添加别名，`alpha2` 为ISO 3166-1 alpha-2代码
+    pub fn add_alias(mut self, alpha2: &str, term: &str) -> Self {
+        self.alias_edits.push(AliasEdit::Add {
+            alpha2: alpha2.to_string(),
+            term: term.to_string(),
+        });
+        self
+    }
+
+    /// 移除国家的别名（不区分大小写）
+    ///
+    /// 只影响别名和简称阶段，国家的正式名称仍会在对应阶段匹配。
+    pub fn remove_alias(mut self, alpha2: &str, term: &str) -> Self {
+        self.alias_edits.push(AliasEdit::Remove {
+            alpha2: alpha2.to_string(),
+            term: term.to_string(),
+        });
+        self
+    }
+
+    /// 添加边界模式，前缀模式会被视为ISO代码左侧的有效边界，后缀模式会被视为保留代码右侧的有效边界
+    pub fn add_pattern(mut self, kind: PatternKind, pattern: &str) -> Self {
+        self.patterns.push((kind, pattern.to_string()));
+        self
+    }
+
+    /// 启用匹配阶段
+    pub fn enable_stage(mut self, stage: MatchStage) -> Self {
+        self.config.stages.insert(stage);
+        self
+    }
+
+    /// 禁用匹配阶段
+    pub fn disable_stage(mut self, stage: MatchStage) -> Self {
+        self.config.stages.remove(&stage);
+        self
+    }
+
+    /// 构建解析器
+    pub fn build(self) -> Result<Parser, ParseError> {
+        let mut configuration = match self.configuration {
+            Some(configuration) => configuration,
+            None => Configuration::load()?,
+        };
+
+        for edit in &self.alias_edits {
+            apply_alias_edit(&mut configuration, edit)?;
+        }
+
+        for (kind, pattern) in &self.patterns {
+            if pattern.is_empty() {
+                return Err(ParseError::config_error("模式不能为空"));
+            }
+            configuration.patterns.add(*kind, pattern);
+        }
+
+        let index = CountryIndex::new(&configuration);
+        Ok(Parser::with_index(self.config, index))
+    }
+}
+
+/// 将别名修改应用到配置
+fn apply_alias_edit(configuration: &mut Configuration, edit: &AliasEdit) -> Result<(), ParseError> {
+    let (alpha2, term) = match edit {
+        AliasEdit::Add { alpha2, term } | AliasEdit::Remove { alpha2, term } => (alpha2, term),
+    };
+
+    if term.trim().is_empty() {
+        return Err(ParseError::config_error(&format!("国家 {} 的别名不能为空", alpha2)));
+    }
+
+    let country = configuration
+        .countries_config
+        .countries
+        .iter_mut()
+        .find(|country| country.alpha2.eq_ignore_ascii_case(alpha2))
+        .ok_or_else(|| ParseError::config_error(&format!("未知的国家代码: {}", alpha2)))?;
+
+    let lower = term.to_lowercase();
+    match edit {
+        AliasEdit::Add { .. } => {
+            if !country.abbreviations.iter().any(|abbr| abbr.to_lowercase() == lower) {
+                country.abbreviations.push(term.clone());
+            }
+        }
+        AliasEdit::Remove { .. } => {
+            country.abbreviations.retain(|abbr| abbr.to_lowercase() != lower);
+        }
+    }
+
+    Ok(())
+}
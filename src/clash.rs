@@ -0,0 +1,180 @@
+//! Clash / Mihomo 配置
+//!
+//! 读取配置中的 `proxies` 列表，按节点名称解析国家，节点的其他字段原样保留；
+//! 将国家分组转换为 `proxy-groups` 配置。需要启用 `clash` 特性。
+
+pub use serde_yaml::{Mapping, Value};
+
+use crate::config::CountryInfo;
+use crate::error::ParseError;
+use crate::group::CountryGroup;
+use crate::rename::{self, RenameTemplate};
+use crate::Parser;
+
+/// 代理节点及其国家
+#[derive(Debug, Clone)]
+pub struct ClashProxy {
+    /// 节点名称
+    pub name: String,
+    /// 节点的原始配置，包含 `name` 在内的所有字段
+    pub config: Mapping,
+    /// 按节点名称解析的国家
+    pub country: Result<CountryInfo, ParseError>,
+}
+
+/// 读取Clash配置中的代理节点，并按节点名称解析国家
+///
+/// 配置中没有 `proxies` 时返回空列表；配置无法解析或 `proxies` 不是列表时返回 `ConfigError`。
+/// 结果与配置中的节点一一对应，格式错误或缺少名称的节点返回单独的错误，不影响其他节点。
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::clash::parse_proxies;
+///
+/// let yaml = r#"
+/// proxies:
+///   - { name: "@HK Vip1", type: ss, server: hk.example.com, port: 443 }
+///   - { type: ss, server: example.com, port: 443 }
+///   - { name: "官方网站", type: ss, server: example.com, port: 443 }
+/// "#;
+///
+/// let proxies = parse_proxies(yaml).unwrap();
+/// let hk = proxies[0].as_ref().unwrap();
+/// assert_eq!(hk.country.as_ref().unwrap().alpha2, "HK");
+/// assert_eq!(hk.config["port"], 443);
+/// assert!(proxies[1].is_err());
+/// assert!(proxies[2].as_ref().unwrap().country.is_err());
+/// ```
+pub fn parse_proxies(yaml: &str) -> Result<Vec<Result<ClashProxy, ParseError>>, ParseError> {
+    Parser::new().parse_clash(yaml)
+}
+
+/// 读取代理节点，使用指定函数解析节点名称
+pub(crate) fn parse_proxies_with<F>(
+    yaml: &str,
+    parse: F,
+) -> Result<Vec<Result<ClashProxy, ParseError>>, ParseError>
+where
+    F: Fn(&str) -> Result<CountryInfo, ParseError>,
+{
+    let document: Value = serde_yaml::from_str(yaml)
+        .map_err(|e| ParseError::config_error(&format!("Clash配置解析失败: {}", e)))?;
+
+    let proxies = match document.get("proxies") {
+        None | Some(Value::Null) => return Ok(Vec::new()),
+        Some(Value::Sequence(proxies)) => proxies,
+        Some(_) => return Err(ParseError::config_error("Clash配置中的 proxies 不是列表")),
+    };
+
+    let proxies = proxies
+        .iter()
+        .enumerate()
+        .map(|(i, proxy)| {
+            let invalid = || ParseError::config_error(&format!("第 {} 个代理节点缺少名称", i + 1));
+            let config = proxy.as_mapping().ok_or_else(invalid)?.clone();
+            let name = config.get("name").and_then(Value::as_str).ok_or_else(invalid)?.to_string();
+            let country = parse(&name);
+            Ok(ClashProxy { name, config, country })
+        })
+        .collect();
+    Ok(proxies)
+}
+
+/// 代理组类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProxyGroupType {
+    /// 手动选择（`select`）
+    Select,
+    /// 自动选择延迟最低的节点（`url-test`）
+    #[default]
+    UrlTest,
+    /// 故障转移（`fallback`）
+    Fallback,
+    /// 负载均衡（`load-balance`）
+    LoadBalance,
+}
+
+impl ProxyGroupType {
+    /// Clash配置中的 `type` 名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Select => "select",
+            Self::UrlTest => "url-test",
+            Self::Fallback => "fallback",
+            Self::LoadBalance => "load-balance",
+        }
+    }
+}
+
+/// 代理组生成选项
+#[derive(Debug, Clone)]
+pub struct ProxyGroupOptions {
+    /// 代理组类型
+    pub group_type: ProxyGroupType,
+    /// 延迟测试地址，`select` 类型不使用
+    pub url: String,
+    /// 延迟测试间隔（秒），`select` 类型不使用
+    pub interval: u32,
+    /// 国家分组的名称模板，`{index}` 为分组序号
+    pub name_template: RenameTemplate,
+    /// 无法解析国家的节点所在分组的名称
+    pub others_name: String,
+}
+
+impl Default for ProxyGroupOptions {
+    fn default() -> Self {
+        Self {
+            group_type: ProxyGroupType::default(),
+            url: "https://www.gstatic.com/generate_204".to_string(),
+            interval: 300,
+            name_template: RenameTemplate::flag_name_en(),
+            others_name: "Others".to_string(),
+        }
+    }
+}
+
+/// 将国家分组转换为Clash的 `proxy-groups` 配置
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::clash::{proxy_groups, ProxyGroupOptions};
+/// use location_rs::group::group_by_country;
+///
+/// let groups = group_by_country(&["HK 01", "香港 02", "官方网站"]);
+/// let yaml = proxy_groups(&groups, &ProxyGroupOptions::default()).unwrap();
+/// assert!(yaml.starts_with("proxy-groups:\n- name: 🇭🇰 Hong Kong\n  type: url-test\n"));
+/// assert!(yaml.contains("- name: Others"));
+/// ```
+pub fn proxy_groups(groups: &[CountryGroup], options: &ProxyGroupOptions) -> Result<String, ParseError> {
+    let names = groups
+        .iter()
+        .enumerate()
+        .map(|(i, group)| match &group.country {
+            Some(country) => options.name_template.render_country(country, i + 1),
+            None => options.others_name.clone(),
+        })
+        .collect();
+
+    let entries: Vec<Value> = rename::deduplicate(names)
+        .into_iter()
+        .zip(groups)
+        .map(|(name, group)| {
+            let mut entry = Mapping::new();
+            entry.insert("name".into(), name.into());
+            entry.insert("type".into(), options.group_type.as_str().into());
+            if options.group_type != ProxyGroupType::Select {
+                entry.insert("url".into(), options.url.clone().into());
+                entry.insert("interval".into(), options.interval.into());
+            }
+            let proxies = group.names.iter().map(|name| Value::from(name.clone())).collect();
+            entry.insert("proxies".into(), Value::Sequence(proxies));
+            Value::Mapping(entry)
+        })
+        .collect();
+
+    let mut document = Mapping::new();
+    document.insert("proxy-groups".into(), Value::Sequence(entries));
+    serde_yaml::to_string(&document).map_err(|e| ParseError::config_error(&format!("Clash配置生成失败: {}", e)))
+}
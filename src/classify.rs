@@ -0,0 +1,337 @@
+//! 信息节点识别
+//!
+//! 订阅中常混有 "剩余流量：120GB"、"套餐到期：2026-12-01"、"官网 example.com" 等不是真实节点的条目。
+//! 按关键词将名称分为普通节点、信息节点、广告节点和过期节点，并解析其中的流量和到期时间。
+//! 订阅信息需要附带流量、日期或天数，能解析出国家且带有节点编号的名称（如 "日本 02 官网推荐"）始终是普通节点。
+
+use std::fmt;
+use std::ops::Range;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::attributes::{numbers, Attribute};
+use crate::parser::{is_word_bounded, FoldedText};
+use crate::Parser;
+
+/// 节点类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    /// 普通节点
+    Normal,
+    /// 流量、到期时间等订阅信息
+    Info,
+    /// 官网、群组等广告
+    Ad,
+    /// 套餐已过期的提示
+    Expired,
+}
+
+impl NodeKind {
+    /// 是否不是真实节点
+    pub fn is_pseudo(self) -> bool {
+        self != NodeKind::Normal
+    }
+}
+
+/// 日期
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// 年
+    pub year: i32,
+    /// 月（1-12）
+    pub month: u32,
+    /// 日（1-31）
+    pub day: u32,
+}
+
+impl Date {
+    /// 当前的UTC日期
+    pub fn today() -> Self {
+        let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    /// 由1970-01-01起的天数计算日期
+    fn from_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// 到期时间
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Expiry {
+    /// 具体日期（"2026-12-01"、"2026年12月1日"）
+    Date(Date),
+    /// 剩余天数（"3天"、"3 days"）
+    Days(u32),
+}
+
+/// 识别结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct Classification {
+    /// 节点类型
+    pub kind: NodeKind,
+    /// 流量，单位为字节（"120GB" 按1024进制换算），只在不是普通节点时解析
+    pub traffic: Option<Attribute<u64>>,
+    /// 到期时间或重置时间，只在不是普通节点时解析
+    pub expiry: Option<Attribute<Expiry>>,
+}
+
+/// 识别使用的关键词，英文关键词不区分大小写且需要完整单词匹配
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassifierConfig {
+    /// 订阅信息关键词
+    pub info_keywords: Vec<String>,
+    /// 广告关键词
+    pub ad_keywords: Vec<String>,
+    /// 过期关键词
+    pub expired_keywords: Vec<String>,
+    /// 判断到期日期是否已过时使用的日期，为 `None` 时使用当前的UTC日期
+    pub today: Option<Date>,
+}
+
+/// 默认的订阅信息关键词
+///
+/// "流量"、"剩余" 等单独出现时多是节点描述（如 "大流量"、"剩余 3 节点"），不作为关键词。
+pub const DEFAULT_INFO_KEYWORDS: &[&str] = &[
+    "剩余流量", "已用流量", "总流量", "到期", "过期时间", "套餐", "重置", "traffic", "expire", "expires",
+    "expiry", "reset", "remaining",
+];
+
+/// 默认的广告关键词
+///
+/// "Telegram"、"客服" 等也常作为节点的用途说明（如 "日本 Telegram 专用"），不作为关键词。
+pub const DEFAULT_AD_KEYWORDS: &[&str] = &["官网", "官方网站", "网址", "官方群", "交流群", "邀请", "推广"];
+
+/// 默认的过期关键词
+pub const DEFAULT_EXPIRED_KEYWORDS: &[&str] = &["已过期", "已到期", "已失效", "expired"];
+
+impl Default for ClassifierConfig {
+    fn default() -> Self {
+        let owned = |keywords: &[&str]| keywords.iter().map(|k| k.to_string()).collect();
+        Self {
+            info_keywords: owned(DEFAULT_INFO_KEYWORDS),
+            ad_keywords: owned(DEFAULT_AD_KEYWORDS),
+            expired_keywords: owned(DEFAULT_EXPIRED_KEYWORDS),
+            today: None,
+        }
+    }
+}
+
+/// 流量单位及对应的字节数
+const TRAFFIC_UNITS: &[(&str, u64)] = &[
+    ("pib", 1 << 50),
+    ("tib", 1 << 40),
+    ("gib", 1 << 30),
+    ("mib", 1 << 20),
+    ("kib", 1 << 10),
+    ("pb", 1 << 50),
+    ("tb", 1 << 40),
+    ("gb", 1 << 30),
+    ("mb", 1 << 20),
+    ("kb", 1 << 10),
+];
+
+/// 天数单位
+const DAY_UNITS: &[&str] = &["days", "day", "天", "日"];
+
+/// 识别节点名称的类型
+///
+/// 依次检查过期、广告和订阅信息关键词；订阅信息需要附带流量、日期或天数，到期日期早于今天时视为过期
+/// （今天的日期可以通过 `ClassifierConfig::today` 指定）。
+/// 能解析出国家且国家后面带有节点编号的名称是普通节点。
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::classify::{classify, Expiry, NodeKind};
+///
+/// let result = classify("剩余流量：120GB");
+/// assert_eq!(result.kind, NodeKind::Info);
+/// assert_eq!(result.traffic.unwrap().value, 120 << 30);
+///
+/// let result = classify("套餐到期：2020-12-01");
+/// assert_eq!(result.kind, NodeKind::Expired);
+/// assert!(matches!(result.expiry.unwrap().value, Expiry::Date(date) if date.year == 2020));
+///
+/// assert_eq!(classify("香港 01").kind, NodeKind::Normal);
+/// assert_eq!(classify("美国 不限流量").kind, NodeKind::Normal);
+/// ```
+pub fn classify(text: &str) -> Classification {
+    Parser::new().classify(text)
+}
+
+/// 使用指定解析器的关键词识别节点名称的类型
+pub(crate) fn classify_with(text: &str, parser: &Parser) -> Classification {
+    let config = &parser.config().classifier;
+    let folded = FoldedText::new(text);
+    let contains = |keywords: &[String]| {
+        keywords.iter().any(|keyword| {
+            let keyword = keyword.trim().to_lowercase();
+            let found = folded
+                .find_all(&keyword)
+                .any(|(start, end)| !keyword.is_ascii() || is_word_bounded(text, start, end));
+            found
+        })
+    };
+
+    let normal = Classification {
+        kind: NodeKind::Normal,
+        traffic: None,
+        expiry: None,
+    };
+    let kind = if contains(&config.expired_keywords) {
+        NodeKind::Expired
+    } else if contains(&config.ad_keywords) {
+        NodeKind::Ad
+    } else if contains(&config.info_keywords) {
+        NodeKind::Info
+    } else {
+        return normal;
+    };
+
+    let traffic = find_traffic(text);
+    let expiry = find_date(text).or_else(|| find_days(text));
+    if kind == NodeKind::Info && traffic.is_none() && expiry.is_none() {
+        return normal;
+    }
+
+    let values: Vec<Range<usize>> = traffic
+        .iter()
+        .map(|t| t.span.clone())
+        .chain(expiry.iter().map(|e| e.span.clone()))
+        .collect();
+    if is_numbered_node(text, parser, &values) {
+        return normal;
+    }
+
+    let today = config.today.unwrap_or_else(Date::today);
+    let past = expiry
+        .as_ref()
+        .is_some_and(|e| matches!(e.value, Expiry::Date(date) if date < today));
+
+    Classification {
+        kind: if kind == NodeKind::Info && past { NodeKind::Expired } else { kind },
+        traffic,
+        expiry,
+    }
+}
+
+/// 检查名称是否能解析出国家，且国家后面带有节点编号（如 "香港 01"、"HK02"）
+///
+/// 国家和编号都不能落在流量、日期或天数中（如 "120GB" 中的 "GB"）。
+fn is_numbered_node(text: &str, parser: &Parser, values: &[Range<usize>]) -> bool {
+    let outside = |start: usize, end: usize| values.iter().all(|v| end <= v.start || v.end <= start);
+    let Ok(m) = parser.parse_detailed(text) else {
+        return false;
+    };
+    if !outside(m.span.start, m.span.end) {
+        return false;
+    }
+
+    numbers(text).any(|(_, start, end)| {
+        start >= m.span.end
+            && outside(start, end)
+            && text[start..end].bytes().all(|b| b.is_ascii_digit())
+            && !text[end..].starts_with(char::is_alphabetic)
+    })
+}
+
+/// 查找 "120GB"、"1.5 TB" 形式的流量
+fn find_traffic(text: &str) -> Option<Attribute<u64>> {
+    numbers(text).find_map(|(number, start, end)| {
+        let rest = text[end..].trim_start_matches(' ');
+        let lower = rest.to_ascii_lowercase();
+        let &(unit, scale) = TRAFFIC_UNITS.iter().find(|(unit, _)| lower.starts_with(unit))?;
+        let unit_end = text.len() - rest.len() + unit.len();
+        if text[unit_end..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+
+        Some(Attribute {
+            value: (number * scale as f64).round() as u64,
+            span: start..unit_end,
+        })
+    })
+}
+
+/// 查找 "2026-12-01"、"2026/12/01"、"2026.12.01"、"2026年12月1日" 形式的日期
+fn find_date(text: &str) -> Option<Attribute<Expiry>> {
+    numbers(text).find_map(|(_, start, _)| {
+        // "2026.12.01" 中的 "2026.12" 会被当作一个数字，只取整数部分
+        let end = start + text[start..].bytes().take_while(u8::is_ascii_digit).count();
+        let year: i32 = text[start..end].parse().ok().filter(|_| end - start == 4)?;
+
+        let rest = &text[end..];
+        let (separator, month_suffix) = match rest.chars().next()? {
+            '-' => ("-", "-"),
+            '/' => ("/", "/"),
+            '.' => (".", "."),
+            '年' => ("年", "月"),
+            _ => return None,
+        };
+        let rest = &rest[separator.len()..];
+        let month_len = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let month: u32 = rest[..month_len].parse().ok()?;
+
+        let rest = rest[month_len..].strip_prefix(month_suffix)?;
+        let day_len = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let day: u32 = rest[..day_len].parse().ok()?;
+        if !(1..=days_in_month(year, month)).contains(&day) {
+            return None;
+        }
+
+        let mut date_end = text.len() - rest.len() + day_len;
+        if separator == "年" && text[date_end..].starts_with('日') {
+            date_end += '日'.len_utf8();
+        }
+        Some(Attribute {
+            value: Expiry::Date(Date { year, month, day }),
+            span: start..date_end,
+        })
+    })
+}
+
+/// 月份的天数，月份无效时为0
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// 查找 "3天"、"3 days" 形式的天数
+fn find_days(text: &str) -> Option<Attribute<Expiry>> {
+    numbers(text).find_map(|(_, start, end)| {
+        let days: u32 = text[start..end].parse().ok()?;
+        let rest = text[end..].trim_start_matches(' ');
+        let lower = rest.to_lowercase();
+        let unit = DAY_UNITS.iter().find(|unit| lower.starts_with(*unit))?;
+        let unit_end = text.len() - rest.len() + unit.len();
+        if unit.is_ascii() && text[unit_end..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+
+        Some(Attribute {
+            value: Expiry::Days(days),
+            span: start..unit_end,
+        })
+    })
+}
@@ -1,6 +1,9 @@
 use serde::Deserialize;
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt;
 use crate::error::ParseError;
+use crate::schema::{self, SchemaVersion};
 
 /// 国家信息配置
 #[derive(Debug, Deserialize, Clone)]
@@ -9,16 +12,259 @@ pub struct CountryInfo {
     pub alpha2: String,
     /// ISO 3166-1 alpha-3代码
     pub alpha3: String,
+    /// ISO 3166-1 数字代码（三位，保留前导零）
+    pub numeric: Option<String>,
     /// 英文名称
     pub name_en: String,
     /// 简体中文名称
     pub name_zh_cn: String,
     /// 繁体中文名称
     pub name_zh_tw: String,
+    /// 所属大洲的M49代码
+    pub region: Option<String>,
+    /// 所属次区域的M49代码
+    pub sub_region: Option<String>,
+    /// 所属中间区域的M49代码（仅部分国家）
+    pub intermediate_region: Option<String>,
+    /// 所属主权国家的alpha-2代码，仅属地（如香港、波多黎各）有该字段
+    pub sovereign: Option<String>,
+    /// 国际电话区号（不含 "+"），与其他国家共用区号时包含区分用的地区号（如泽西岛为 "441534"）
+    #[serde(default)]
+    pub calling_codes: Vec<String>,
+    /// 其他语言的名称，按语言区域分组
+    #[serde(default)]
+    pub names: HashMap<Locale, Vec<String>>,
     /// 国家简称和别称
     pub abbreviations: Vec<String>,
 }
 
+/// 语言区域，使用BCP 47语言标签（如 "ja"）
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(transparent)]
+pub struct Locale(Cow<'static, str>);
+
+impl Locale {
+    /// 日语
+    pub const JA: Locale = Locale(Cow::Borrowed("ja"));
+    /// 韩语
+    pub const KO: Locale = Locale(Cow::Borrowed("ko"));
+    /// 俄语
+    pub const RU: Locale = Locale(Cow::Borrowed("ru"));
+
+    /// 使用语言标签创建语言区域
+    pub fn new(tag: &str) -> Self {
+        Locale(Cow::Owned(tag.to_string()))
+    }
+
+    /// 语言标签
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl CountryInfo {
+    /// 由alpha-2代码生成的旗帜emoji（如 "🇭🇰"）
+    pub fn flag(&self) -> String {
+        self.alpha2
+            .chars()
+            .filter_map(|c| char::from_u32(0x1F1E6 + (c.to_ascii_uppercase() as u32).checked_sub('A' as u32)?))
+            .collect()
+    }
+
+    /// 获取指定语言区域的名称
+    pub fn names_in(&self, locale: &Locale) -> &[String] {
+        self.names.get(locale).map_or(&[], Vec::as_slice)
+    }
+
+    /// 检查国家是否属于指定地区
+    pub fn in_region(&self, region: &RegionInfo) -> bool {
+        [&self.region, &self.sub_region, &self.intermediate_region]
+            .into_iter()
+            .any(|code| code.as_deref() == Some(region.code.as_str()))
+            || region.members.iter().any(|member| member.eq_ignore_ascii_case(&self.alpha2))
+    }
+}
+
+/// 地区信息，基于联合国M49标准
+#[derive(Debug, Deserialize, Clone)]
+pub struct RegionInfo {
+    /// M49地区代码，非M49分组（如中东）使用自定义代码
+    pub code: String,
+    /// 英文名称
+    pub name_en: String,
+    /// 简体中文名称
+    pub name_zh_cn: String,
+    /// 繁体中文名称
+    pub name_zh_tw: String,
+    /// 上级地区代码
+    pub parent: Option<String>,
+    /// 地区别称
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// 非M49分组的成员（alpha-2代码）
+    #[serde(default)]
+    pub members: Vec<String>,
+}
+
+/// 一级行政区信息，基于ISO 3166-2标准
+#[derive(Debug, Deserialize, Clone)]
+pub struct SubdivisionInfo {
+    /// ISO 3166-2代码（如 "US-CA"）
+    pub code: String,
+    /// 英文名称
+    pub name_en: String,
+    /// 简体中文名称
+    pub name_zh_cn: String,
+    /// 繁体中文名称
+    pub name_zh_tw: String,
+    /// 简称和别称
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl SubdivisionInfo {
+    /// 所属国家的alpha-2代码
+    pub fn alpha2(&self) -> &str {
+        self.code.split('-').next().unwrap_or_default()
+    }
+}
+
+/// 行政区配置
+#[derive(Debug, Deserialize, Clone)]
+pub struct SubdivisionsConfig {
+    /// 行政区信息列表
+    pub subdivisions: Vec<SubdivisionInfo>,
+}
+
+/// 城市信息，主要收录常见的机房所在城市
+#[derive(Debug, Deserialize, Clone)]
+pub struct CityInfo {
+    /// 英文名称
+    pub name_en: String,
+    /// 简体中文名称
+    pub name_zh_cn: String,
+    /// 繁体中文名称
+    pub name_zh_tw: String,
+    /// 所属国家的alpha-2代码
+    pub country: String,
+    /// 所属一级行政区的ISO 3166-2代码
+    pub subdivision: Option<String>,
+    /// 别称和其他拼写
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// 城市配置
+#[derive(Debug, Deserialize, Clone)]
+pub struct CitiesConfig {
+    /// 城市信息列表
+    pub cities: Vec<CityInfo>,
+}
+
+/// 机场信息，包括IATA机场代码和城市代码
+#[derive(Debug, Deserialize, Clone)]
+pub struct AirportInfo {
+    /// IATA代码（如 "LAX"）
+    pub code: String,
+    /// 英文名称
+    pub name_en: String,
+    /// 所属国家的alpha-2代码
+    pub country: String,
+    /// 所在城市的英文名称，对应城市配置中的城市
+    pub city: Option<String>,
+}
+
+/// 机场配置
+#[derive(Debug, Deserialize, Clone)]
+pub struct AirportsConfig {
+    /// 机场信息列表
+    pub airports: Vec<AirportInfo>,
+}
+
+/// 拼音配置，收录国家名称和别称中出现的汉字
+#[derive(Debug, Deserialize, Clone)]
+pub struct PinyinConfig {
+    /// 汉字到带声调拼音的映射
+    pub pinyin: HashMap<String, String>,
+}
+
+/// 保留代码的类型
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ReservedKind {
+    /// 特别保留代码（如 UK、EU）
+    ExceptionallyReserved,
+    /// 过渡保留代码，原国家已解体或更名（如 YU、AN）
+    TransitionallyReserved,
+    /// 已删除且不再保留的历史代码（如 DD）
+    Historical,
+}
+
+/// ISO 3166-1 保留代码或历史代码
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReservedCodeInfo {
+    /// alpha-2代码
+    pub code: String,
+    /// alpha-3代码（如有）
+    pub alpha3: Option<String>,
+    /// 代码类型
+    pub kind: ReservedKind,
+    /// 英文名称
+    pub name_en: String,
+    /// 简体中文名称
+    pub name_zh_cn: String,
+    /// 繁体中文名称
+    pub name_zh_tw: String,
+    /// 现在对应的国家（alpha-2代码），第一个为主要继承国；为空时表示非国家实体（如欧盟）
+    pub successors: Vec<String>,
+}
+
+impl ReservedCodeInfo {
+    /// 是否是非国家实体（如欧盟、联合国）
+    pub fn is_pseudo_entity(&self) -> bool {
+        self.successors.is_empty()
+    }
+
+    /// 将非国家实体表示为国家信息
+    pub(crate) fn to_country_info(&self) -> CountryInfo {
+        CountryInfo {
+            alpha2: self.code.clone(),
+            alpha3: self.alpha3.clone().unwrap_or_default(),
+            numeric: None,
+            name_en: self.name_en.clone(),
+            name_zh_cn: self.name_zh_cn.clone(),
+            name_zh_tw: self.name_zh_tw.clone(),
+            region: None,
+            sub_region: None,
+            intermediate_region: None,
+            sovereign: None,
+            calling_codes: Vec::new(),
+            names: HashMap::new(),
+            abbreviations: Vec::new(),
+        }
+    }
+}
+
+/// 保留代码配置
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReservedCodesConfig {
+    /// 保留代码和历史代码列表
+    pub reserved_codes: Vec<ReservedCodeInfo>,
+}
+
+/// 地区配置
+#[derive(Debug, Deserialize, Clone)]
+pub struct RegionsConfig {
+    /// 地区信息列表
+    pub regions: Vec<RegionInfo>,
+}
+
 /// 解析器设置
 #[derive(Debug, Deserialize, Clone)]
 pub struct ParserSettings {
@@ -33,12 +279,35 @@ pub struct ParserSettings {
 /// 模式配置
 #[derive(Debug, Deserialize, Clone)]
 pub struct PatternConfig {
-    /// 前缀模式
+    /// 前缀模式，视为ISO代码左侧的有效边界（如 "|US"）
     pub prefix_patterns: Vec<String>,
-    /// 后缀模式
+    /// 后缀模式，视为保留代码右侧的有效边界（如 "UKVip"）
     pub suffix_patterns: Vec<String>,
 }
 
+/// 模式类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternKind {
+    /// 前缀模式
+    Prefix,
+    /// 后缀模式
+    Suffix,
+}
+
+impl PatternConfig {
+    /// 添加模式，已存在的模式不会重复添加
+    pub fn add(&mut self, kind: PatternKind, pattern: &str) {
+        let patterns = match kind {
+            PatternKind::Prefix => &mut self.prefix_patterns,
+            PatternKind::Suffix => &mut self.suffix_patterns,
+        };
+
+        if !patterns.iter().any(|p| p == pattern) {
+            patterns.push(pattern.to_string());
+        }
+    }
+}
+
 /// 国家配置
 #[derive(Debug, Deserialize, Clone)]
 pub struct CountriesConfig {
@@ -53,6 +322,18 @@ pub struct CountriesConfig {
 pub struct Configuration {
     /// 国家配置
     pub countries_config: CountriesConfig,
+    /// 地区配置
+    pub regions_config: RegionsConfig,
+    /// 行政区配置
+    pub subdivisions_config: SubdivisionsConfig,
+    /// 城市配置
+    pub cities_config: CitiesConfig,
+    /// 机场配置
+    pub airports_config: AirportsConfig,
+    /// 拼音配置
+    pub pinyin_config: PinyinConfig,
+    /// 保留代码配置
+    pub reserved_codes_config: ReservedCodesConfig,
     /// 模式配置
     pub patterns: PatternConfig,
     /// 解析器设置
@@ -62,10 +343,46 @@ pub struct Configuration {
 impl Configuration {
     /// 从多个嵌入的JSON文件加载配置
     pub fn load() -> Result<Self, ParseError> {
-        // 加载国家配置
-        let countries_str = include_str!("../resources/countries.json");
-        let countries_config: CountriesConfig = serde_json::from_str(countries_str)
+        Self::from_countries_json(include_str!("../resources/countries.json"))
+    }
+
+    /// 使用自定义的国家配置JSON加载配置，模式配置和解析器设置使用内置文件
+    pub fn from_countries_json(countries_str: &str) -> Result<Self, ParseError> {
+        // 加载国家配置，旧版本的数据集会先迁移到当前版本
+        let countries_value: serde_json::Value = serde_json::from_str(countries_str)
             .map_err(|e| ParseError::config_error(&format!("国家配置解析失败: {}", e)))?;
+        let countries_config: CountriesConfig = serde_json::from_value(schema::migrate(countries_value)?)
+            .map_err(|e| ParseError::config_error(&format!("国家配置解析失败: {}", e)))?;
+        
+        // 加载地区配置
+        let regions_str = include_str!("../resources/regions.json");
+        let regions_config: RegionsConfig = serde_json::from_str(regions_str)
+            .map_err(|e| ParseError::config_error(&format!("地区配置解析失败: {}", e)))?;
+        
+        // 加载行政区配置
+        let subdivisions_str = include_str!("../resources/subdivisions.json");
+        let subdivisions_config: SubdivisionsConfig = serde_json::from_str(subdivisions_str)
+            .map_err(|e| ParseError::config_error(&format!("行政区配置解析失败: {}", e)))?;
+        
+        // 加载城市配置
+        let cities_str = include_str!("../resources/cities.json");
+        let cities_config: CitiesConfig = serde_json::from_str(cities_str)
+            .map_err(|e| ParseError::config_error(&format!("城市配置解析失败: {}", e)))?;
+        
+        // 加载机场配置
+        let airports_str = include_str!("../resources/airports.json");
+        let airports_config: AirportsConfig = serde_json::from_str(airports_str)
+            .map_err(|e| ParseError::config_error(&format!("机场配置解析失败: {}", e)))?;
+        
+        // 加载拼音配置
+        let pinyin_str = include_str!("../resources/pinyin.json");
+        let pinyin_config: PinyinConfig = serde_json::from_str(pinyin_str)
+            .map_err(|e| ParseError::config_error(&format!("拼音配置解析失败: {}", e)))?;
+        
+        // 加载保留代码配置
+        let reserved_codes_str = include_str!("../resources/reserved_codes.json");
+        let reserved_codes_config: ReservedCodesConfig = serde_json::from_str(reserved_codes_str)
+            .map_err(|e| ParseError::config_error(&format!("保留代码配置解析失败: {}", e)))?;
         
         // 加载模式配置
         let patterns_str = include_str!("../resources/patterns.json");
@@ -79,6 +396,12 @@ impl Configuration {
         
         Ok(Configuration {
             countries_config,
+            regions_config,
+            subdivisions_config,
+            cities_config,
+            airports_config,
+            pinyin_config,
+            reserved_codes_config,
             patterns,
             settings,
         })
@@ -128,8 +451,184 @@ impl Configuration {
         &self.countries_config.countries
     }
     
+    /// 获取所有地区信息
+    pub fn get_regions(&self) -> &[RegionInfo] {
+        &self.regions_config.regions
+    }
+
+    /// 按代码、名称或别称查找地区（不区分大小写）
+    pub fn find_region(&self, query: &str) -> Option<&RegionInfo> {
+        let query = query.trim().to_lowercase();
+
+        self.get_regions().iter().find(|region| {
+            [&region.code, &region.name_en, &region.name_zh_cn, &region.name_zh_tw]
+                .into_iter()
+                .chain(&region.aliases)
+                .any(|term| term.to_lowercase() == query)
+        })
+    }
+
+    /// 获取属于指定地区的所有国家，地区可以是代码、名称或别称
+    pub fn countries_in_region(&self, query: &str) -> Vec<&CountryInfo> {
+        match self.find_region(query) {
+            Some(region) => self
+                .get_countries()
+                .iter()
+                .filter(|country| country.in_region(region))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// 按国际电话区号查找国家，区号可以带 "+" 前缀或后续号码
+    ///
+    /// 共用的区号返回所有候选国家，直接使用该区号的国家排在前面。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use location_rs::Configuration;
+    ///
+    /// let configuration = Configuration::load().unwrap();
+    /// let candidates = configuration.countries_by_calling_code("+7");
+    /// assert_eq!(candidates[0].alpha2, "RU");
+    /// assert!(candidates.iter().any(|c| c.alpha2 == "KZ"));
+    /// ```
+    pub fn countries_by_calling_code(&self, code: &str) -> Vec<&CountryInfo> {
+        let countries = self.get_countries();
+        let digits: String = code
+            .trim()
+            .trim_start_matches('+')
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+
+        calling_code_matches(countries, &digits)
+            .into_iter()
+            .map(|(i, _)| &countries[i])
+            .collect()
+    }
+
+    /// 获取主权国家的所有属地，国家可以是alpha-2或alpha-3代码（不区分大小写）
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use location_rs::Configuration;
+    ///
+    /// let configuration = Configuration::load().unwrap();
+    /// let territories = configuration.territories_of("DK");
+    /// assert!(territories.iter().any(|c| c.alpha2 == "GL"));
+    /// ```
+    pub fn territories_of(&self, country: &str) -> Vec<&CountryInfo> {
+        let country = country.trim();
+        let Some(sovereign) = self.get_countries().iter().find(|c| {
+            c.alpha2.eq_ignore_ascii_case(country) || c.alpha3.eq_ignore_ascii_case(country)
+        }) else {
+            return Vec::new();
+        };
+
+        self.get_countries()
+            .iter()
+            .filter(|c| c.sovereign.as_deref() == Some(sovereign.alpha2.as_str()))
+            .collect()
+    }
+
+    /// 获取所有行政区信息
+    pub fn get_subdivisions(&self) -> &[SubdivisionInfo] {
+        &self.subdivisions_config.subdivisions
+    }
+
+    /// 获取指定国家的行政区
+    pub fn subdivisions_of(&self, alpha2: &str) -> Vec<&SubdivisionInfo> {
+        self.get_subdivisions()
+            .iter()
+            .filter(|subdivision| subdivision.alpha2().eq_ignore_ascii_case(alpha2))
+            .collect()
+    }
+
+    /// 获取所有城市信息
+    pub fn get_cities(&self) -> &[CityInfo] {
+        &self.cities_config.cities
+    }
+
+    /// 获取所有机场信息
+    pub fn get_airports(&self) -> &[AirportInfo] {
+        &self.airports_config.airports
+    }
+
+    /// 获取所有保留代码和历史代码
+    pub fn get_reserved_codes(&self) -> &[ReservedCodeInfo] {
+        &self.reserved_codes_config.reserved_codes
+    }
+
+    /// 按alpha-2或alpha-3代码查找保留代码（不区分大小写）
+    pub fn find_reserved_code(&self, code: &str) -> Option<&ReservedCodeInfo> {
+        let code = code.trim();
+        self.get_reserved_codes().iter().find(|reserved| {
+            reserved.code.eq_ignore_ascii_case(code)
+                || reserved.alpha3.as_deref().is_some_and(|alpha3| alpha3.eq_ignore_ascii_case(code))
+        })
+    }
+
+    /// 获取文本中每个汉字的带声调拼音，包含拼音表之外的字符时返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use location_rs::Configuration;
+    ///
+    /// let configuration = Configuration::load().unwrap();
+    /// assert_eq!(configuration.pinyin_of("香港").unwrap(), ["xiāng", "gǎng"]);
+    /// ```
+    pub fn pinyin_of(&self, text: &str) -> Option<Vec<&str>> {
+        let mut buf = [0; 4];
+        text.chars()
+            .map(|c| self.pinyin_config.pinyin.get(&*c.encode_utf8(&mut buf)).map(String::as_str))
+            .collect()
+    }
+
     /// 获取配置版本
     pub fn get_version(&self) -> &str {
         &self.countries_config.version
     }
-}
\ No newline at end of file
+
+    /// 获取数据集版本
+    pub fn schema_version(&self) -> Result<SchemaVersion, ParseError> {
+        SchemaVersion::parse(&self.countries_config.version)
+    }
+}
+/// 按号码查找区号对应的国家，返回国家位置以及是否直接使用该区号
+///
+/// 先找出作为号码前缀的最长区号；号码恰好是该区号时，还包括使用更长区号的国家（如 "+1" 下的波多黎各）。
+pub(crate) fn calling_code_matches(countries: &[CountryInfo], digits: &str) -> Vec<(usize, bool)> {
+    let codes = || {
+        countries
+            .iter()
+            .enumerate()
+            .flat_map(|(i, country)| country.calling_codes.iter().map(move |code| (i, code.as_str())))
+    };
+
+    let Some(matched) = codes()
+        .map(|(_, code)| code)
+        .filter(|code| !code.is_empty() && digits.starts_with(code))
+        .max_by_key(|code| code.len())
+    else {
+        return Vec::new();
+    };
+
+    let mut exact: Vec<(usize, bool)> = Vec::new();
+    let mut extended: Vec<(usize, bool)> = Vec::new();
+    for (i, code) in codes() {
+        if code == matched {
+            exact.push((i, true));
+        } else if digits == matched && code.starts_with(matched) {
+            extended.push((i, false));
+        }
+    }
+    exact.dedup();
+    extended.retain(|&(i, _)| !exact.iter().any(|&(j, _)| i == j));
+    extended.dedup();
+    exact.extend(extended);
+    exact
+}
@@ -0,0 +1,173 @@
+//! Clash过滤正则
+//!
+//! 由国家的国旗、别称、名称和ISO代码生成Clash / Mihomo `filter` 使用的正则表达式，
+//! 各词条的边界规则与解析器一致。正则只使用RE2语法（Go的 `regexp`），不含环视。
+
+use crate::config::{CountryInfo, ReservedKind};
+use crate::error::ParseError;
+use crate::parser::{Script, BOUNDARY_CLASS};
+use crate::{MatchStage, Parser};
+
+/// 生成匹配指定国家节点名称的正则表达式
+///
+/// 包含国旗、别称、中英文名称、其他语言名称、alpha-3和alpha-2代码以及以该国家为继承国的保留代码，
+/// 只包含解析器启用的阶段对应的词条。拼音、行政区和城市等间接推断出国家的词条不包含在内。
+///
+/// 正则按词条是否出现判断，不考虑解析器的阶段优先级，同时提到两个国家的名称会被两个国家的正则匹配。
+/// 运营商名称中的代码和名称（如 "CN2"、"中国联通"）同样会被匹配。
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::{filter::filter_regex, parse_country_code};
+///
+/// let hk = parse_country_code("HK").unwrap();
+/// let regex = filter_regex(&hk).unwrap();
+/// assert!(regex.starts_with("(?i)🇭🇰|香港|"));
+/// ```
+pub fn filter_regex(country: &CountryInfo) -> Result<String, ParseError> {
+    Parser::new().filter_regex(country)
+}
+
+/// 使用指定解析器的配置和数据生成正则表达式
+pub(crate) fn filter_regex_with(country: &CountryInfo, parser: &Parser) -> Result<String, ParseError> {
+    let index = parser.index()?;
+    let config = parser.config();
+    let enabled = |stage: MatchStage| config.stages.contains(&stage);
+    let left = left_boundary(index.prefix_patterns());
+    let right = right_boundary(index.suffix_patterns());
+
+    let mut terms = Vec::new();
+    let mut push = |term: String| {
+        if !term.is_empty() && !terms.contains(&term) {
+            terms.push(term);
+        }
+    };
+
+    // 启用属地归并时，属地的节点也解析为该国家
+    let countries: Vec<&CountryInfo> = std::iter::once(country).chain(index.territories_of(country, config)).collect();
+    for country in countries {
+        if enabled(MatchStage::Flag) && index.country_by_alpha2(&country.alpha2).is_some() {
+            push(country.flag());
+        }
+        if enabled(MatchStage::Abbreviation) {
+            country.abbreviations.iter().for_each(|abbr| push(abbreviation(abbr)));
+        }
+        if enabled(MatchStage::TraditionalChinese) {
+            push(escape(&country.name_zh_tw));
+        }
+        if enabled(MatchStage::SimplifiedChinese) {
+            push(escape(&country.name_zh_cn));
+        }
+        if enabled(MatchStage::English) {
+            push(escape(&country.name_en));
+        }
+        if enabled(MatchStage::Localized) {
+            country.names.values().flatten().for_each(|name| push(localized(name.trim())));
+        }
+
+        // 代码前面需要边界，后面可以跟数字或其他字母（如 "HK01"、"USA"）
+        let codes: Vec<String> = [(MatchStage::Alpha3, &country.alpha3), (MatchStage::Alpha2, &country.alpha2)]
+            .into_iter()
+            .filter(|&(stage, code)| enabled(stage) && !code.is_empty())
+            .map(|(_, code)| escape(code))
+            .collect();
+        if !codes.is_empty() {
+            push(format!("{}(?:{})", left, codes.join("|")));
+        }
+
+        // 保留代码需要大写且完整单词匹配
+        if enabled(MatchStage::Reserved) {
+            let reserved: Vec<String> = index
+                .reserved_codes_of(country)
+                .filter(|code| config.historical_reserved || code.kind == ReservedKind::ExceptionallyReserved)
+                .flat_map(|code| code.alpha3.iter().chain(std::iter::once(&code.code)))
+                .map(|code| escape(code))
+                .collect();
+            if !reserved.is_empty() {
+                push(format!("{}(?-i:{}){}", left, reserved.join("|"), right));
+            }
+        }
+    }
+
+    Ok(format!("(?i){}", terms.join("|")))
+}
+
+/// 代码左侧的边界：文本开头、边界字符、数字或前缀边界模式
+fn left_boundary(prefix_patterns: &[String]) -> String {
+    let mut class = format!(r"{}\pN", BOUNDARY_CLASS);
+    let mut alternatives = vec!["^".to_string()];
+    for pattern in prefix_patterns.iter().filter(|p| !p.is_empty()) {
+        let mut chars = pattern.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => {
+                if !class.contains(c) {
+                    if matches!(c, '\\' | ']' | '[' | '^' | '-') {
+                        class.push('\\');
+                    }
+                    class.push(c);
+                }
+            }
+            _ => alternatives.push(escape(pattern)),
+        }
+    }
+
+    alternatives.push(format!("[{}]", class));
+    format!("(?:{})", alternatives.join("|"))
+}
+
+/// 保留代码右侧的边界：文本结尾、非字母或后缀边界模式（区分大小写）
+fn right_boundary(suffix_patterns: &[String]) -> String {
+    let mut alternatives = vec!["[^A-Za-z]".to_string(), "$".to_string()];
+    alternatives.extend(
+        suffix_patterns
+            .iter()
+            .filter(|p| !p.is_empty())
+            .map(|p| format!("(?-i:{})", escape(p))),
+    );
+    format!("(?:{})", alternatives.join("|"))
+}
+
+/// 别称，纯ASCII的别称（如 "UK"）两侧不能紧跟字母
+fn abbreviation(abbr: &str) -> String {
+    if abbr.is_ascii() {
+        format!("(?:^|[^A-Za-z]){}(?:[^A-Za-z]|$)", escape(abbr))
+    } else {
+        escape(abbr)
+    }
+}
+
+/// 其他语言的名称，两侧按文字类型加边界
+fn localized(name: &str) -> String {
+    let (Some(first), Some(last)) = (name.chars().next(), name.chars().next_back()) else {
+        return String::new();
+    };
+
+    let boundary = |c: char, left: bool| {
+        let script = Script::of(c);
+        match script.class() {
+            Some(class) if script.needs_boundary(left) => {
+                if left {
+                    format!("(?:^|[^{}])", class)
+                } else {
+                    format!("(?:[^{}]|$)", class)
+                }
+            }
+            _ => String::new(),
+        }
+    };
+
+    format!("{}{}{}", boundary(first, true), escape(name), boundary(last, false))
+}
+
+/// 转义正则元字符
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if r"\.+*?()|[]{}^$".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -0,0 +1,71 @@
+//! 按国家分组
+//!
+//! 将节点按解析出的国家分组，国家按在列表中首次出现的顺序排列，无法解析国家的节点和信息节点（如 "剩余流量：10GB"）
+//! 归入最后的其他分组。
+
+use crate::config::CountryInfo;
+use crate::Parser;
+
+/// 同一国家的节点
+#[derive(Debug, Clone)]
+pub struct CountryGroup {
+    /// 分组的国家，其他分组为 `None`
+    pub country: Option<CountryInfo>,
+    /// 节点名称，保持原列表中的顺序
+    pub names: Vec<String>,
+}
+
+impl CountryGroup {
+    /// 是否为无法解析国家的其他分组
+    pub fn is_others(&self) -> bool {
+        self.country.is_none()
+    }
+}
+
+/// 按国家分组节点
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::group::group_by_country;
+///
+/// let groups = group_by_country(&["HK 01", "日本 01", "香港 02", "官方网站"]);
+/// assert_eq!(groups.len(), 3);
+/// assert_eq!(groups[0].country.as_ref().unwrap().alpha2, "HK");
+/// assert_eq!(groups[0].names, ["HK 01", "香港 02"]);
+/// assert!(groups[2].is_others());
+/// ```
+pub fn group_by_country<S: AsRef<str>>(names: &[S]) -> Vec<CountryGroup> {
+    Parser::new().group_by_country(names)
+}
+
+/// 使用指定解析器分组节点
+pub(crate) fn group_with<S: AsRef<str>>(names: &[S], parser: &Parser) -> Vec<CountryGroup> {
+    let mut groups: Vec<CountryGroup> = Vec::new();
+    let mut others = Vec::new();
+
+    for name in names {
+        let name = name.as_ref();
+        let pseudo = parser.classify(name).kind.is_pseudo();
+        let Some(country) = (!pseudo).then(|| parser.parse(name).ok()).flatten() else {
+            others.push(name.to_string());
+            continue;
+        };
+
+        match groups.iter_mut().find(|group| group.country.as_ref().is_some_and(|c| c.alpha2 == country.alpha2)) {
+            Some(group) => group.names.push(name.to_string()),
+            None => groups.push(CountryGroup {
+                country: Some(country),
+                names: vec![name.to_string()],
+            }),
+        }
+    }
+
+    if !others.is_empty() {
+        groups.push(CountryGroup {
+            country: None,
+            names: others,
+        });
+    }
+    groups
+}
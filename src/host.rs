@@ -0,0 +1,113 @@
+//! 主机名解析
+//!
+//! 从主机名或URL推断国家：先检查主机标签中的国家代码（如 "hk01"、"jp-tyo"），
+//! 再检查国家代码顶级域名（如 ".jp"）。常被用作通用域名的顶级域名（如 ".io"）默认不参与推断。
+//! 主机标签按解析器的alpha-3和alpha-2规则匹配，与常见单词相同的代码（如 "my"、"id"）需要带有编号。
+
+use crate::config::CountryInfo;
+use crate::error::ParseError;
+use crate::parser::{validate_input, CountryIndex};
+use crate::ParserConfig;
+
+/// 默认视为非地理含义的国家代码顶级域名
+pub const DEFAULT_NON_GEOGRAPHIC_TLDS: &[&str] = &[
+    "ai", "am", "cc", "co", "fm", "gg", "io", "la", "ly", "me", "nu", "sh", "to", "tv", "ws",
+];
+
+/// 与alpha-2代码不同的国家代码顶级域名
+const TLD_ALIASES: &[(&str, &str)] = &[("uk", "GB")];
+
+/// 常见的二级域名（如 "co.jp" 中的 "co"），不作为主机标签解析
+const SECOND_LEVEL_LABELS: &[&str] = &["ac", "co", "com", "edu", "go", "gov", "ne", "net", "or", "org"];
+
+/// 与常见单词或服务名称相同的alpha-2代码，单独作为主机标签时不视为国家（"my.provider.com"），
+/// 带有编号时仍然是国家（"my1.provider.com"）
+const LABEL_STOPLIST: &[&str] = &[
+    "ad", "ai", "am", "as", "at", "be", "by", "cc", "do", "fm", "id", "in", "is", "it", "me", "ms", "my", "no",
+    "pm", "so", "to", "tv",
+];
+
+/// 解析主机名或URL中的国家
+pub fn parse_host(host: &str) -> Result<CountryInfo, ParseError> {
+    let index = CountryIndex::embedded()?;
+    parse_host_with_index(host, &ParserConfig::default(), &index)
+}
+
+/// 使用指定索引解析主机名或URL中的国家
+pub(crate) fn parse_host_with_index(
+    input: &str,
+    config: &ParserConfig,
+    index: &CountryIndex,
+) -> Result<CountryInfo, ParseError> {
+    validate_input(input)?;
+
+    let host = host_of(input).ok_or_else(|| ParseError::invalid_input(input))?;
+    let labels: Vec<&str> = host.split('.').collect();
+    let (tld, names) = match labels.split_last() {
+        Some((tld, names)) if !names.is_empty() => (Some(*tld), names),
+        _ => (None, &labels[..]),
+    };
+
+    // 顶级域名前面的二级域名不是主机标签
+    let names = match names.split_last() {
+        Some((last, rest)) if tld.is_some() && SECOND_LEVEL_LABELS.contains(last) => rest,
+        _ => names,
+    };
+
+    // 只使用代码阶段，其他设置与解析器一致
+    let code_config = ParserConfig {
+        stages: config.stages.iter().copied().filter(|stage| stage.is_code()).collect(),
+        ..config.clone()
+    };
+    let from_label = names
+        .iter()
+        .flat_map(|label| label.split(['-', '_']))
+        .find_map(|token| label_country(token, &code_config, index));
+
+    let from_tld = || {
+        let tld = tld?;
+        if config.non_geographic_tlds.contains(tld) {
+            return None;
+        }
+
+        let code = TLD_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == tld)
+            .map_or(tld, |(_, alpha2)| alpha2);
+        let country = (code.len() == 2).then(|| index.country_by_alpha2(code)).flatten()?;
+        Some(index.sovereign_of(country, config).unwrap_or(country).clone())
+    };
+
+    from_label.or_else(from_tld).ok_or_else(|| ParseError::not_found(input))
+}
+
+/// 从URL或主机名中取出小写的主机名，去掉协议、用户信息、端口和路径
+fn host_of(input: &str) -> Option<String> {
+    let rest = input.trim();
+    let rest = rest.split_once("://").map_or(rest, |(_, rest)| rest);
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = authority.rsplit('@').next().unwrap_or_default();
+
+    // IPv6地址不包含域名
+    if host.starts_with('[') {
+        return None;
+    }
+
+    let host = host.split(':').next().unwrap_or_default().trim_end_matches('.');
+    (!host.is_empty()).then(|| host.to_lowercase())
+}
+
+/// 主机标签中的国家代码：代码位于开头，后面只能跟着编号（如 "hk01"、"usa2"）
+fn label_country(token: &str, config: &ParserConfig, index: &CountryIndex) -> Option<CountryInfo> {
+    let (stage, m) = index.find(token, config)?;
+    let m = index.to_match(stage, m, config);
+    let number = &token[m.span.end..];
+    if m.span.start != 0 || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if number.is_empty() && LABEL_STOPLIST.contains(&token) {
+        return None;
+    }
+
+    Some(m.country)
+}
@@ -6,10 +6,54 @@
 pub mod error;
 pub mod config;
 pub mod parser;
+pub mod builder;
+pub mod validation;
+pub mod schema;
+pub mod host;
+pub mod rename;
+pub mod group;
+pub mod filter;
+pub mod relay;
+pub mod attributes;
+pub mod classify;
+#[cfg(feature = "metadata")]
+pub mod metadata;
+#[cfg(feature = "clash")]
+pub mod clash;
+#[cfg(feature = "links")]
+pub mod links;
+#[cfg(feature = "subscription")]
+pub mod subscription;
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
 
 // 重新导出主要类型
 pub use error::ParseError;
-pub use config::{Configuration, CountryInfo, ParserSettings};
+pub use config::{
+    AirportInfo, CityInfo, Configuration, CountryInfo, Locale, ParserSettings, PatternKind,
+    RegionInfo, ReservedCodeInfo, ReservedKind, SubdivisionInfo,
+};
+pub use parser::{CountryMatch, IataConflict, Location, MatchStage};
+pub use builder::ParserBuilder;
+pub use schema::{SchemaVersion, CURRENT_SCHEMA_VERSION};
+pub use host::DEFAULT_NON_GEOGRAPHIC_TLDS;
+pub use rename::RenameTemplate;
+pub use group::CountryGroup;
+pub use relay::RelayMatch;
+pub use attributes::{Carrier, NodeAttributes};
+pub use classify::{Classification, ClassifierConfig, NodeKind};
+pub use validation::{ValidationIssue, ValidationIssueKind, ValidationReport};
+#[cfg(feature = "metadata")]
+pub use metadata::CountryMetadata;
+#[cfg(feature = "clash")]
+pub use clash::{ClashProxy, ProxyGroupOptions, ProxyGroupType};
+#[cfg(feature = "links")]
+pub use links::{Protocol, ShareLink};
+#[cfg(feature = "subscription")]
+pub use subscription::{Subscription, SubscriptionFormat};
+
+use parser::CountryIndex;
 
 
 
@@ -27,6 +71,52 @@ pub fn parse_country_code(text: &str) -> Result<CountryInfo, ParseError> {
     parser::parse_country_code(text)
 }
 
+/// 解析文本中的国家，文本中只有大洲或次区域时返回地区
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::{parse_location, Location};
+///
+/// assert!(matches!(parse_location("欧洲节点"), Ok(Location::Region(r)) if r.code == "150"));
+/// assert!(matches!(parse_location("欧洲-德国"), Ok(Location::Country(m)) if m.country.alpha2 == "DE"));
+/// ```
+pub fn parse_location(text: &str) -> Result<Location, ParseError> {
+    parser::parse_location(text)
+}
+
+/// 解析文本，返回包含匹配阶段、位置和行政区的详细结果
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::parse_detailed;
+///
+/// let result = parse_detailed("加州节点").unwrap();
+/// assert_eq!(result.country.alpha2, "US");
+/// assert_eq!(result.subdivision.unwrap().code, "US-CA");
+/// ```
+pub fn parse_detailed(text: &str) -> Result<CountryMatch, ParseError> {
+    parser::parse_detailed(text)
+}
+
+/// 解析主机名或URL中的国家
+///
+/// 先检查主机标签中的国家代码，再检查国家代码顶级域名。
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::parse_host;
+///
+/// assert_eq!(parse_host("https://jp1.example.com/sub").unwrap().alpha2, "JP");
+/// assert_eq!(parse_host("node.hk").unwrap().alpha2, "HK");
+/// assert!(parse_host("node.example.io").is_err());
+/// ```
+pub fn parse_host(host: &str) -> Result<CountryInfo, ParseError> {
+    host::parse_host(host)
+}
+
 /// 解析器配置
 #[derive(Debug, Clone)]
 pub struct ParserConfig {
@@ -34,6 +124,18 @@ pub struct ParserConfig {
     pub case_sensitive: bool,
     /// 是否启用模糊匹配
     pub fuzzy_match: bool,
+    /// 启用的匹配阶段，按 [`MatchStage`] 的优先级依次尝试
+    pub stages: BTreeSet<MatchStage>,
+    /// alpha-3代码与IATA代码冲突时的处理策略
+    pub iata_conflict: IataConflict,
+    /// 不参与主机名解析的国家代码顶级域名（小写）
+    pub non_geographic_tlds: BTreeSet<String>,
+    /// 将属地（如香港、波多黎各）归并到主权国家
+    pub resolve_sovereign: bool,
+    /// 匹配过渡保留代码和历史代码（如 "YU"、"CS"、"DD"），默认只匹配特别保留代码
+    pub historical_reserved: bool,
+    /// 识别信息节点使用的关键词
+    pub classifier: ClassifierConfig,
 }
 
 impl Default for ParserConfig {
@@ -41,31 +143,126 @@ impl Default for ParserConfig {
         Self {
             case_sensitive: false,
             fuzzy_match: true,
+            stages: MatchStage::default_stages(),
+            iata_conflict: IataConflict::default(),
+            non_geographic_tlds: DEFAULT_NON_GEOGRAPHIC_TLDS.iter().map(|tld| tld.to_string()).collect(),
+            resolve_sovereign: false,
+            historical_reserved: false,
+            classifier: ClassifierConfig::default(),
         }
     }
 }
 
 /// 解析器实例
+///
+/// 解析器创建后不可变，可以在线程间共享。
+#[derive(Debug, Clone)]
 pub struct Parser {
     config: ParserConfig,
+    /// 自定义索引，为空时使用内置数据集
+    index: Option<Arc<CountryIndex>>,
 }
 
 impl Parser {
     /// 使用默认配置创建解析器
     pub fn new() -> Self {
-        Self {
-            config: ParserConfig::default(),
-        }
+        Self::with_config(ParserConfig::default())
     }
     
     /// 使用自定义配置创建解析器
     pub fn with_config(config: ParserConfig) -> Self {
-        Self { config }
+        Self { config, index: None }
+    }
+
+    /// 使用自定义索引创建解析器
+    pub(crate) fn with_index(config: ParserConfig, index: CountryIndex) -> Self {
+        Self {
+            config,
+            index: Some(Arc::new(index)),
+        }
+    }
+
+    /// 创建解析器构建器
+    pub fn builder() -> ParserBuilder {
+        ParserBuilder::new()
     }
     
     /// 解析文本中的国家代码
     pub fn parse(&self, text: &str) -> Result<CountryInfo, ParseError> {
-        parser::parse_country_code_with_config(text, &self.config)
+        self.parse_detailed(text).map(|m| m.country)
+    }
+
+    /// 解析文本，返回包含匹配阶段、位置和行政区的详细结果
+    pub fn parse_detailed(&self, text: &str) -> Result<CountryMatch, ParseError> {
+        let index = self.index()?;
+        parser::parse_with_index(text, &self.config, &index)
+    }
+
+    /// 解析文本中的国家，文本中只有大洲或次区域时返回地区
+    pub fn parse_location(&self, text: &str) -> Result<Location, ParseError> {
+        let index = self.index()?;
+        parser::parse_location_with_index(text, &self.config, &index)
+    }
+
+    /// 解析主机名或URL中的国家
+    pub fn parse_host(&self, host: &str) -> Result<CountryInfo, ParseError> {
+        let index = self.index()?;
+        host::parse_host_with_index(host, &self.config, &index)
+    }
+
+    /// 线路感知的解析，中转节点返回入口和出口位置，主要国家为出口国家
+    pub fn parse_relay(&self, text: &str) -> Result<RelayMatch, ParseError> {
+        relay::parse_relay_with(text, self)
+    }
+
+    /// 识别信息节点、广告节点和过期节点，并解析其中的流量和到期时间
+    pub fn classify(&self, text: &str) -> Classification {
+        classify::classify_with(text, self)
+    }
+
+    /// 按模板重命名节点，保证输出名称不重复
+    pub fn rename<S: AsRef<str>>(&self, titles: &[S], template: &RenameTemplate) -> Vec<String> {
+        rename::rename_with(titles, template, self)
+    }
+
+    /// 按国家分组节点，国家按首次出现的顺序排列，无法解析国家的节点和信息节点归入最后的其他分组
+    pub fn group_by_country<S: AsRef<str>>(&self, names: &[S]) -> Vec<CountryGroup> {
+        group::group_with(names, self)
+    }
+
+    /// 生成匹配指定国家节点名称的Clash `filter` 正则表达式
+    pub fn filter_regex(&self, country: &CountryInfo) -> Result<String, ParseError> {
+        filter::filter_regex_with(country, self)
+    }
+
+    /// 读取Clash配置中的代理节点，并按节点名称解析国家，格式错误的节点单独返回错误
+    #[cfg(feature = "clash")]
+    pub fn parse_clash(&self, yaml: &str) -> Result<Vec<Result<ClashProxy, ParseError>>, ParseError> {
+        clash::parse_proxies_with(yaml, |name| self.parse(name))
+    }
+
+    /// 解析分享链接，按节点名称解析国家，名称中没有国家时使用服务器主机名
+    #[cfg(feature = "links")]
+    pub fn parse_link(&self, uri: &str) -> Result<ShareLink, ParseError> {
+        links::parse_link_with(uri, self)
+    }
+
+    /// 解析订阅内容，自动识别格式并按节点名称解析国家
+    #[cfg(feature = "subscription")]
+    pub fn decode_subscription(&self, bytes: &[u8]) -> Result<Subscription, ParseError> {
+        subscription::decode_with(bytes, self)
+    }
+
+    /// 获取解析器配置
+    pub fn config(&self) -> &ParserConfig {
+        &self.config
+    }
+
+    fn index(&self) -> Result<Arc<CountryIndex>, ParseError> {
+        match &self.index {
+            Some(index) => Ok(Arc::clone(index)),
+            None => CountryIndex::embedded(),
+        }
     }
 }
 
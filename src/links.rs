@@ -0,0 +1,216 @@
+//! 分享链接
+//!
+//! 解析 `ss://`、`vmess://`、`trojan://`、`vless://`、`hysteria2://` 和 `tuic://` 分享链接，
+//! 提取节点名称、服务器和端口，并按名称解析国家，名称中没有国家时使用服务器主机名。
+//! 需要启用 `links` 特性。
+
+use std::borrow::Cow;
+use std::fmt;
+
+use base64::alphabet;
+use base64::engine::{DecodePaddingMode, Engine, GeneralPurpose, GeneralPurposeConfig};
+use percent_encoding::percent_decode_str;
+use serde_json::Value;
+
+use crate::config::CountryInfo;
+use crate::error::ParseError;
+use crate::Parser;
+
+/// 分享链接的协议
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Protocol {
+    /// Shadowsocks（`ss://`）
+    Shadowsocks,
+    /// VMess（`vmess://`）
+    Vmess,
+    /// Trojan（`trojan://`）
+    Trojan,
+    /// VLESS（`vless://`）
+    Vless,
+    /// Hysteria2（`hysteria2://` 或 `hy2://`）
+    Hysteria2,
+    /// TUIC（`tuic://`）
+    Tuic,
+}
+
+impl Protocol {
+    /// 按链接的scheme获取协议（不区分大小写）
+    pub fn from_scheme(scheme: &str) -> Option<Self> {
+        match scheme.to_ascii_lowercase().as_str() {
+            "ss" => Some(Self::Shadowsocks),
+            "vmess" => Some(Self::Vmess),
+            "trojan" => Some(Self::Trojan),
+            "vless" => Some(Self::Vless),
+            "hysteria2" | "hy2" => Some(Self::Hysteria2),
+            "tuic" => Some(Self::Tuic),
+            _ => None,
+        }
+    }
+
+    /// 协议名称，与Clash配置中的 `type` 字段一致
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Shadowsocks => "ss",
+            Self::Vmess => "vmess",
+            Self::Trojan => "trojan",
+            Self::Vless => "vless",
+            Self::Hysteria2 => "hysteria2",
+            Self::Tuic => "tuic",
+        }
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 解析后的分享链接
+#[derive(Debug, Clone)]
+pub struct ShareLink {
+    /// 协议
+    pub protocol: Protocol,
+    /// 节点名称，链接中没有名称时为空
+    pub name: String,
+    /// 服务器地址（IPv6地址不含方括号）
+    pub server: String,
+    /// 服务器端口
+    pub port: u16,
+    /// 按节点名称解析的国家，名称中没有国家时使用服务器主机名
+    pub country: Result<CountryInfo, ParseError>,
+}
+
+/// 解析分享链接
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::links::{parse_link, Protocol};
+///
+/// let link = parse_link("trojan://secret@jp.example.com:443?sni=example.com#%E6%97%A5%E6%9C%AC%2001").unwrap();
+/// assert_eq!(link.protocol, Protocol::Trojan);
+/// assert_eq!(link.name, "日本 01");
+/// assert_eq!(link.port, 443);
+/// assert_eq!(link.country.unwrap().alpha2, "JP");
+/// ```
+pub fn parse_link(uri: &str) -> Result<ShareLink, ParseError> {
+    Parser::new().parse_link(uri)
+}
+
+/// 使用指定解析器解析分享链接
+pub(crate) fn parse_link_with(uri: &str, parser: &Parser) -> Result<ShareLink, ParseError> {
+    let invalid = || ParseError::invalid_input(uri);
+
+    let uri = uri.trim();
+    let (scheme, rest) = uri.split_once("://").ok_or_else(invalid)?;
+    let protocol = Protocol::from_scheme(scheme).ok_or_else(invalid)?;
+
+    let (name, server, port) = match protocol {
+        Protocol::Vmess if !rest.contains('@') => parse_vmess(rest).ok_or_else(invalid)?,
+        Protocol::Shadowsocks => parse_shadowsocks(rest).ok_or_else(invalid)?,
+        _ => {
+            let (rest, name) = split_fragment(rest);
+            let default_port = (protocol == Protocol::Hysteria2).then_some(443);
+            let (server, port) = parse_authority(rest, default_port).ok_or_else(invalid)?;
+            (name, server, port)
+        }
+    };
+
+    let country = country_of(parser, &name, &server);
+    Ok(ShareLink {
+        protocol,
+        name,
+        server,
+        port,
+        country,
+    })
+}
+
+/// 按节点名称解析国家，名称中没有国家时使用服务器主机名
+pub(crate) fn country_of(parser: &Parser, name: &str, server: &str) -> Result<CountryInfo, ParseError> {
+    parser.parse(name).or_else(|e| parser.parse_host(server).map_err(|_| e))
+}
+
+/// 解码Base64，支持标准和URL安全字母表，填充可有可无，忽略空白
+pub(crate) fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    const CONFIG: GeneralPurposeConfig =
+        GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+    const STANDARD: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, CONFIG);
+    const URL_SAFE: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, CONFIG);
+
+    let text: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    if text.contains(['-', '_']) {
+        URL_SAFE.decode(text).ok()
+    } else {
+        STANDARD.decode(text).ok()
+    }
+}
+
+/// 拆分URL片段，返回片段之前的部分和解码后的名称
+fn split_fragment(rest: &str) -> (&str, String) {
+    match rest.split_once('#') {
+        Some((rest, fragment)) => (rest, percent_decode(fragment).trim().to_string()),
+        None => (rest, String::new()),
+    }
+}
+
+fn percent_decode(text: &str) -> Cow<'_, str> {
+    percent_decode_str(text).decode_utf8_lossy()
+}
+
+/// 解析 `[userinfo@]host[:port][/path][?query]` 中的主机和端口
+fn parse_authority(rest: &str, default_port: Option<u16>) -> Option<(String, u16)> {
+    let authority = rest.split(['/', '?']).next()?;
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, host_port)| host_port);
+
+    let (host, port) = match host_port.strip_prefix('[') {
+        Some(ipv6) => {
+            let (host, port) = ipv6.split_once(']')?;
+            (host, port.strip_prefix(':'))
+        }
+        None => match host_port.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        },
+    };
+
+    let port = match port {
+        Some(port) => port.parse().ok()?,
+        None => default_port?,
+    };
+    (!host.is_empty()).then(|| (host.to_string(), port))
+}
+
+/// 解析Shadowsocks链接：SIP002格式（`ss://userinfo@host:port#name`）
+/// 或整体Base64编码的旧格式（`ss://BASE64(method:password@host:port)#name`）
+fn parse_shadowsocks(rest: &str) -> Option<(String, String, u16)> {
+    let (rest, name) = split_fragment(rest);
+
+    if rest.contains('@') {
+        let (server, port) = parse_authority(rest, None)?;
+        return Some((name, server, port));
+    }
+
+    let encoded = rest.split(['/', '?']).next()?;
+    let decoded = String::from_utf8(decode_base64(encoded)?).ok()?;
+    let (server, port) = parse_authority(&decoded, None)?;
+    Some((name, server, port))
+}
+
+/// 解析VMess链接：Base64编码的JSON，名称在 `ps` 字段
+fn parse_vmess(rest: &str) -> Option<(String, String, u16)> {
+    let encoded = rest.split(['#', '?']).next()?;
+    let json: Value = serde_json::from_slice(&decode_base64(encoded)?).ok()?;
+
+    let text = |key: &str| match json.get(key)? {
+        Value::String(text) => Some(text.trim().to_string()),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    };
+
+    let name = text("ps").unwrap_or_default();
+    let server = text("add").filter(|server| !server.is_empty())?;
+    let port = text("port")?.parse().ok()?;
+    Some((name, server, port))
+}
@@ -0,0 +1,106 @@
+//! 国家元数据
+//!
+//! 首都、货币、国家代码顶级域名、官方语言和IANA时区。需要启用 `metadata` 特性，
+//! 数据只在首次使用时加载。
+
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::config::CountryInfo;
+use crate::error::ParseError;
+
+/// 国家元数据
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub struct CountryMetadata {
+    /// ISO 3166-1 alpha-2代码
+    pub alpha2: String,
+    /// 首都的英文名称，无常住人口的地区没有首都
+    pub capital: Option<String>,
+    /// ISO 4217 货币代码
+    pub currencies: Vec<String>,
+    /// 国家代码顶级域名（含 "."），未委派的地区为空
+    pub tlds: Vec<String>,
+    /// 官方语言的ISO 639-1代码
+    pub languages: Vec<String>,
+    /// IANA时区，按人口从多到少排列
+    pub timezones: Vec<String>,
+}
+
+/// 元数据配置文件
+#[derive(Debug, Deserialize)]
+struct MetadataConfig {
+    metadata: Vec<CountryMetadata>,
+}
+
+/// 获取内置的全部国家元数据
+pub fn all_metadata() -> Result<&'static [CountryMetadata], ParseError> {
+    static EMBEDDED: OnceLock<Result<Vec<CountryMetadata>, ParseError>> = OnceLock::new();
+
+    EMBEDDED
+        .get_or_init(|| {
+            serde_json::from_str::<MetadataConfig>(include_str!("../resources/metadata.json"))
+                .map(|config| config.metadata)
+                .map_err(|e| ParseError::config_error(&format!("元数据配置解析失败: {}", e)))
+        })
+        .as_deref()
+        .map_err(Clone::clone)
+}
+
+/// 按alpha-2代码查找国家元数据（不区分大小写）
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::metadata::metadata_of;
+///
+/// let jp = metadata_of("jp").unwrap();
+/// assert_eq!(jp.currencies, ["JPY"]);
+/// assert_eq!(jp.timezones, ["Asia/Tokyo"]);
+/// ```
+pub fn metadata_of(alpha2: &str) -> Option<&'static CountryMetadata> {
+    let alpha2 = alpha2.trim();
+    all_metadata().ok()?.iter().find(|metadata| metadata.alpha2.eq_ignore_ascii_case(alpha2))
+}
+
+impl CountryInfo {
+    /// 获取内置的国家元数据，保留代码等非国家实体没有元数据
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use location_rs::parse_country_code;
+    ///
+    /// let country = parse_country_code("英国节点").unwrap();
+    /// assert_eq!(country.capital(), Some("London"));
+    /// assert_eq!(country.tlds(), [".uk"]);
+    /// ```
+    pub fn metadata(&self) -> Option<&'static CountryMetadata> {
+        metadata_of(&self.alpha2)
+    }
+
+    /// 首都的英文名称
+    pub fn capital(&self) -> Option<&'static str> {
+        self.metadata()?.capital.as_deref()
+    }
+
+    /// ISO 4217 货币代码
+    pub fn currencies(&self) -> &'static [String] {
+        self.metadata().map_or(&[], |metadata| &metadata.currencies)
+    }
+
+    /// 国家代码顶级域名（含 "."）
+    pub fn tlds(&self) -> &'static [String] {
+        self.metadata().map_or(&[], |metadata| &metadata.tlds)
+    }
+
+    /// 官方语言的ISO 639-1代码
+    pub fn languages(&self) -> &'static [String] {
+        self.metadata().map_or(&[], |metadata| &metadata.languages)
+    }
+
+    /// IANA时区
+    pub fn timezones(&self) -> &'static [String] {
+        self.metadata().map_or(&[], |metadata| &metadata.timezones)
+    }
+}
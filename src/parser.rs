@@ -1,214 +1,1248 @@
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+use std::sync::{Arc, OnceLock};
+
+use crate::attributes::{find_carriers, Attribute, Carrier};
 use crate::error::ParseError;
-use crate::config::{Configuration, CountryInfo};
+use crate::config::{
+    calling_code_matches, AirportInfo, CityInfo, Configuration, CountryInfo, RegionInfo,
+    ReservedCodeInfo, ReservedKind, SubdivisionInfo,
+};
 use crate::ParserConfig;
 
-/// 主要的解析函数
-pub fn parse_country_code(text: &str) -> Result<CountryInfo, ParseError> {
-    parse_country_code_with_config(text, &ParserConfig::default())
+/// 匹配阶段
+///
+/// 枚举的声明顺序即匹配优先级：排在前面的阶段先被尝试。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchStage {
+    /// 国旗emoji（如 "🇭🇰"）
+    Flag,
+    /// 别名和简称
+    Abbreviation,
+    /// 繁体中文名称
+    TraditionalChinese,
+    /// 简体中文名称
+    SimplifiedChinese,
+    /// 英文名称
+    English,
+    /// 其他语言的名称（日语、韩语、俄语等），只尝试文本中出现的文字
+    Localized,
+    /// 中文名称的拼音（如 "Xianggang"、"Xiāng Gǎng"），需要完整单词匹配
+    Pinyin,
+    /// ISO 3166-1 保留代码（如 "UK"、"EU"），需要大写且完整单词匹配；
+    /// 过渡保留代码和历史代码（如 "YU"、"DD"）需要启用 [`ParserConfig::historical_reserved`]
+    Reserved,
+    /// ISO 3166-1 alpha-3代码
+    Alpha3,
+    /// ISO 3166-1 alpha-2代码
+    Alpha2,
+    /// IATA机场代码和城市代码（如 "LAX"、"TYO"），默认不启用
+    Iata,
+    /// 国际电话区号（如 "+852"），默认不启用
+    CallingCode,
+    /// ISO 3166-2 一级行政区，由行政区推断国家
+    Subdivision,
+    /// 机房所在城市，由城市推断国家
+    City,
 }
 
-/// 使用配置的解析函数
-pub fn parse_country_code_with_config(
-    text: &str,
-    _config: &ParserConfig,
-) -> Result<CountryInfo, ParseError> {
-    // 输入验证
-    if text.trim().is_empty() {
-        return Err(ParseError::invalid_input("输入文本为空"));
+impl MatchStage {
+    /// 所有匹配阶段，按优先级排列
+    pub const ALL: [MatchStage; 14] = [
+        MatchStage::Flag,
+        MatchStage::Abbreviation,
+        MatchStage::TraditionalChinese,
+        MatchStage::SimplifiedChinese,
+        MatchStage::English,
+        MatchStage::Localized,
+        MatchStage::Pinyin,
+        MatchStage::Reserved,
+        MatchStage::Alpha3,
+        MatchStage::Alpha2,
+        MatchStage::Iata,
+        MatchStage::CallingCode,
+        MatchStage::Subdivision,
+        MatchStage::City,
+    ];
+
+    /// 默认启用的匹配阶段
+    pub fn default_stages() -> BTreeSet<MatchStage> {
+        Self::ALL.iter().copied().filter(|stage| stage.is_default()).collect()
     }
-    
-    if text.len() > 1024 {
-        return Err(ParseError::invalid_input("输入文本过长"));
+
+    /// 是否默认启用，IATA和电话区号阶段需要手动启用
+    pub fn is_default(self) -> bool {
+        !matches!(self, MatchStage::Iata | MatchStage::CallingCode)
     }
-    
-    // 加载配置
-    let config_data = Configuration::load()
-        .map_err(|e| ParseError::config_error(&format!("配置加载失败: {}", e)))?;
-    
-    // 注意：这里不再需要预处理文本变量，因为我们直接在各个匹配函数中处理
-    
-    // 按照指定优先级进行匹配：别名和简称 > 繁体中文名 > 简体中文名 > 英文名 > alpha3 > alpha2
-    // 首先尝试精确匹配
-    match parse_abbreviations(text, &config_data) {
-        Ok(country_info) => return Ok(country_info),
-        Err(_) => {},
-    }
-    
-    match parse_traditional_chinese_names(text, &config_data) {
-        Ok(country_info) => return Ok(country_info),
-        Err(_) => {},
-    }
-    
-    match parse_simplified_chinese_names(text, &config_data) {
-        Ok(country_info) => return Ok(country_info),
-        Err(_) => {},
-    }
-    
-    match parse_english_names(text, &config_data) {
-        Ok(country_info) => return Ok(country_info),
-        Err(_) => {},
-    }
-    
-    // 对于代码匹配，我们需要支持更灵活的情况，包括数字后缀
-    // 首先尝试 alpha3 代码（3字符）
-    let chars: Vec<char> = text.chars().collect();
-    for i in 0..chars.len().saturating_sub(2) {
-        if chars[i].is_alphabetic() && chars[i+1].is_alphabetic() && chars[i+2].is_alphabetic() {
-            let slice: String = chars[i..i+3].iter().collect();
-            let slice_upper = slice.to_uppercase();
-            
-            for country in config_data.get_countries() {
-                if country.alpha3 == slice_upper {
-                    // 检查是否是有效的ISO代码位置，允许后面跟着数字
-                    let mut valid = true;
-                    if i > 0 {
-                        let prev_char = chars[i-1];
-                        if !is_boundary_char(prev_char) && !prev_char.is_numeric() {
-                            valid = false;
-                        }
-                    }
-                    
-                    if valid {
-                        return Ok(country.clone());
-                    }
-                }
+
+    /// 是否是ISO代码阶段
+    pub fn is_code(self) -> bool {
+        matches!(self, MatchStage::Alpha3 | MatchStage::Alpha2)
+    }
+}
+
+/// alpha-3代码与IATA代码冲突时的处理策略
+///
+/// 部分IATA代码同时也是alpha-3代码，如 "FRA" 既是法国的alpha-3代码，也是法兰克福机场的代码。
+/// 仅在启用 [`MatchStage::Iata`] 时生效。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IataConflict {
+    /// 按alpha-3代码解析（"FRA" → FR）
+    #[default]
+    PreferAlpha3,
+    /// 按IATA代码解析（"FRA" → DE）
+    PreferIata,
+}
+
+/// 详细的解析结果
+#[derive(Debug, Clone)]
+pub struct CountryMatch {
+    /// 匹配到的国家或地区
+    pub country: CountryInfo,
+    /// 命中的匹配阶段
+    pub stage: MatchStage,
+    /// 命中文本在原文中的字节范围
+    pub span: Range<usize>,
+    /// 文本中提到的一级行政区
+    pub subdivision: Option<SubdivisionInfo>,
+    /// 文本中提到的城市
+    pub city: Option<CityInfo>,
+    /// 文本中的IATA代码对应的机场
+    pub airport: Option<AirportInfo>,
+    /// 匹配到的保留代码或历史代码，此时 `country` 为主要继承国或非国家实体
+    pub reserved: Option<ReservedCodeInfo>,
+    /// 启用 [`ParserConfig::resolve_sovereign`] 时，被归并到主权国家的属地
+    pub territory: Option<CountryInfo>,
+    /// 文本中提到的运营商（如 "中国移动"、"CN2"），运营商名称不会被识别为国家
+    pub carrier: Option<Carrier>,
+}
+
+/// 解析得到的位置
+#[derive(Debug, Clone)]
+pub enum Location {
+    /// 具体的国家或地区
+    Country(Box<CountryMatch>),
+    /// 大洲或次区域，仅在文本中没有具体国家时返回
+    Region(RegionInfo),
+}
+
+/// 单个阶段的匹配结果（字节偏移）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct StageMatch {
+    /// 国家在索引中的位置
+    pub country: usize,
+    /// 匹配起始位置
+    pub start: usize,
+    /// 匹配结束位置
+    pub end: usize,
+    /// 行政区在索引中的位置
+    pub subdivision: Option<usize>,
+    /// 城市在索引中的位置
+    pub city: Option<usize>,
+    /// 机场在索引中的位置
+    pub airport: Option<usize>,
+    /// 保留代码在索引中的位置
+    pub reserved: Option<usize>,
+    /// 文本中第一个运营商
+    pub carrier: Option<Carrier>,
+}
+
+impl StageMatch {
+    fn new(country: usize, start: usize, end: usize) -> Self {
+        Self {
+            country,
+            start,
+            end,
+            subdivision: None,
+            city: None,
+            airport: None,
+            reserved: None,
+            carrier: None,
+        }
+    }
+}
+
+/// 小写化后的文本，保留到原文字节偏移的映射
+pub(crate) struct FoldedText {
+    text: String,
+    /// 每个字节对应的原文字符的字节范围
+    spans: Vec<(usize, usize)>,
+}
+
+impl FoldedText {
+    pub(crate) fn new(text: &str) -> Self {
+        Self::fold(text, |_| false, |c| c)
+    }
+
+    /// 拼音形式的文本：去掉声调和空格，"ü" 写作 "v"
+    fn romanized(text: &str) -> Self {
+        Self::fold(text, |c| c == ' ', strip_tone)
+    }
+
+    /// 小写化文本，跳过 `skip` 匹配的字符，并用 `map` 转换每个字符
+    fn fold(text: &str, skip: fn(char) -> bool, map: fn(char) -> char) -> Self {
+        let mut folded = String::with_capacity(text.len());
+        let mut spans = Vec::with_capacity(text.len());
+
+        for (pos, c) in text.char_indices() {
+            if skip(c) {
+                continue;
+            }
+            for lower in c.to_lowercase() {
+                let before = folded.len();
+                folded.push(map(lower));
+                spans.extend(std::iter::repeat((pos, pos + c.len_utf8())).take(folded.len() - before));
             }
         }
+
+        Self { text: folded, spans }
     }
-    
-    // 然后尝试 alpha2 代码（2字符）
-    for i in 0..chars.len().saturating_sub(1) {
-        if chars[i].is_alphabetic() && chars[i+1].is_alphabetic() {
-            let slice: String = chars[i..i+2].iter().collect();
-            let slice_upper = slice.to_uppercase();
-            
-            for country in config_data.get_countries() {
-                if country.alpha2 == slice_upper {
-                    // 检查是否是有效的ISO代码位置，允许后面跟着数字
-                    let mut valid = true;
-                    if i > 0 {
-                        let prev_char = chars[i-1];
-                        if !is_boundary_char(prev_char) && !prev_char.is_numeric() {
-                            valid = false;
-                        }
-                    }
-                    
-                    if valid {
-                        return Ok(country.clone());
-                    }
-                }
+
+    /// 查找已小写化词条的所有出现位置，返回原文中的字节范围
+    pub(crate) fn find_all<'a>(&'a self, term: &'a str) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.text
+            .match_indices(term)
+            .filter(|(_, m)| !m.is_empty())
+            .map(move |(pos, m)| (self.spans[pos].0, self.spans[pos + m.len() - 1].1))
+    }
+}
+
+/// 文字类型，用于其他语言名称的匹配
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Script {
+    Latin,
+    Cyrillic,
+    Kana,
+    Hangul,
+    Han,
+    Other,
+}
+
+impl Script {
+    pub(crate) fn of(c: char) -> Self {
+        match c {
+            'a'..='z' | 'A'..='Z' | '\u{00C0}'..='\u{024F}' => Script::Latin,
+            '\u{0400}'..='\u{04FF}' => Script::Cyrillic,
+            // 中点 "・" 用于分隔单词
+            '\u{30FB}' => Script::Other,
+            '\u{3040}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}' => Script::Kana,
+            '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7AF}' => Script::Hangul,
+            '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' => Script::Han,
+            _ => Script::Other,
+        }
+    }
+
+    /// 词条在该文字一侧是否需要边界：相邻字符不能是同一种文字
+    ///
+    /// 韩语名称后面常直接跟着助词或其他词，只检查左侧；汉字不分词，不检查边界。
+    pub(crate) fn needs_boundary(self, left: bool) -> bool {
+        match self {
+            Script::Latin | Script::Cyrillic | Script::Kana => true,
+            Script::Hangul => left,
+            Script::Han | Script::Other => false,
+        }
+    }
+
+    /// 该文字的字符范围，写作正则字符类的内容，与 [`Script::of`] 一致
+    pub(crate) fn class(self) -> Option<&'static str> {
+        match self {
+            Script::Latin => Some(r"a-zA-Z\x{C0}-\x{24F}"),
+            Script::Cyrillic => Some(r"\x{400}-\x{4FF}"),
+            Script::Kana => Some(r"\x{3040}-\x{30FA}\x{30FC}-\x{30FF}\x{31F0}-\x{31FF}\x{FF66}-\x{FF9F}"),
+            Script::Hangul => Some(r"\x{1100}-\x{11FF}\x{3130}-\x{318F}\x{AC00}-\x{D7AF}"),
+            Script::Han => Some(r"\x{3400}-\x{4DBF}\x{4E00}-\x{9FFF}\x{F900}-\x{FAFF}"),
+            Script::Other => None,
+        }
+    }
+}
+
+/// 其他语言的名称词条
+#[derive(Debug, Clone)]
+struct LocalizedTerm {
+    /// 小写化的名称
+    text: String,
+    country: usize,
+    /// 首字符的文字
+    first: Script,
+    /// 末字符的文字
+    last: Script,
+}
+
+impl LocalizedTerm {
+    fn new(name: &str, country: usize) -> Option<Self> {
+        let first = Script::of(name.chars().next()?);
+        let last = Script::of(name.chars().next_back()?);
+        Some(Self { text: name.to_lowercase(), country, first, last })
+    }
+
+    /// 检查原文中的范围是否满足词条两侧的边界
+    fn is_bounded(&self, text: &str, start: usize, end: usize) -> bool {
+        let prev = text[..start].chars().next_back();
+        let next = text[end..].chars().next();
+
+        let left = !self.first.needs_boundary(true) || !prev.is_some_and(|c| Script::of(c) == self.first);
+        let right = !self.last.needs_boundary(false) || !next.is_some_and(|c| Script::of(c) == self.last);
+        left && right
+    }
+}
+
+/// 拼音词条
+#[derive(Debug, Clone)]
+struct PinyinTerm {
+    /// 不带声调的拼音
+    text: String,
+    country: usize,
+    /// 各音节在词条中的结束位置
+    syllable_ends: Vec<usize>,
+}
+
+impl PinyinTerm {
+    /// 检查原文中的空格是否都落在音节之间（"Xiang Gang" 可以匹配，"Xian Ggang" 不可以）
+    fn is_syllabified(&self, text: &str, start: usize, end: usize) -> bool {
+        let mut letters = 0;
+        text[start..end].chars().all(|c| {
+            if c == ' ' {
+                letters == 0 || self.syllable_ends.contains(&letters)
+            } else {
+                letters += 1;
+                true
+            }
+        })
+    }
+}
+
+/// 地名词条（地区、行政区）
+#[derive(Debug, Clone)]
+struct PlaceTerm {
+    /// 小写化的词条
+    text: String,
+    /// 地名在索引中的位置
+    target: usize,
+    /// ASCII词条需要完整单词匹配
+    word: bool,
+}
+
+impl PlaceTerm {
+    /// 将地名的所有名称加入词条列表
+    fn collect<'a, I>(terms: &mut Vec<PlaceTerm>, target: usize, names: I)
+    where
+        I: IntoIterator<Item = &'a String>,
+    {
+        for name in names {
+            if !name.trim().is_empty() {
+                terms.push(PlaceTerm {
+                    text: name.to_lowercase(),
+                    target,
+                    word: name.is_ascii(),
+                });
             }
         }
     }
-    
-    // 最后，尝试使用简化的边界检查再次匹配ISO代码
-    for country in config_data.get_countries() {
-        // 检查 alpha3 代码
-        if text.to_uppercase().contains(&country.alpha3) {
-            let pattern = country.alpha3.to_string();
-            if let Some(pos) = text.to_uppercase().find(&pattern) {
-                let start = pos;
-                let end = pos + pattern.len();
-                
-                // 简化的边界检查
-                let prev_valid = start == 0 || is_boundary_char(text.chars().nth(start-1).unwrap_or(' '));
-                let next_valid = end >= text.len() || is_boundary_char(text.chars().nth(end).unwrap_or(' ')) || text.chars().nth(end).unwrap_or(' ').is_numeric();
-                
-                if prev_valid && next_valid {
-                    return Ok(country.clone());
+}
+
+/// 单次解析的上下文
+struct Context<'a> {
+    text: &'a str,
+    folded: FoldedText,
+    /// 文本中地名所占的范围，落在更长地名内部的匹配会被忽略
+    places: Vec<(usize, usize)>,
+    /// 文本中的IATA代码，仅在启用IATA阶段时记录
+    airports: Vec<(usize, usize, usize)>,
+    /// 冲突的代码按IATA代码解析
+    prefer_iata: bool,
+    /// 匹配过渡保留代码和历史代码
+    historical_reserved: bool,
+    /// 文本中的运营商名称，与其重叠的匹配会被忽略
+    carriers: Vec<Attribute<Carrier>>,
+}
+
+impl Context<'_> {
+    /// 检查范围是否完全落在一个更长的地名内部，或与运营商名称重叠
+    fn is_shadowed(&self, start: usize, end: usize) -> bool {
+        self.places
+            .iter()
+            .any(|&(s, e)| s <= start && end <= e && e - s > end - start)
+            || self.carriers.iter().any(|c| c.span.start < end && start < c.span.end)
+    }
+
+    /// 检查范围是否是应按IATA代码解析的冲突代码
+    fn is_preferred_airport(&self, start: usize, end: usize) -> bool {
+        self.prefer_iata && self.airports.iter().any(|&(_, s, e)| s == start && e == end)
+    }
+
+    /// 查找词条的出现位置，跳过不满足单词边界或被地名覆盖的位置
+    fn find_term(&self, term: &str, word: bool) -> Option<(usize, usize)> {
+        self.folded.find_all(term).find(|&(start, end)| {
+            (!word || is_word_bounded(self.text, start, end)) && !self.is_shadowed(start, end)
+        })
+    }
+}
+
+/// 国家匹配索引
+///
+/// 在构建时对配置中的词条做一次预处理，解析时不再重复加载配置。
+#[derive(Debug, Clone)]
+pub(crate) struct CountryIndex {
+    /// 国家信息，末尾是保留代码中的非国家实体
+    countries: Vec<CountryInfo>,
+    /// 数据集中的国家数量，不包括非国家实体
+    country_count: usize,
+    /// 小写化的别名和简称
+    abbreviations: Vec<(String, usize)>,
+    /// 小写化的英文名称
+    english_names: Vec<(String, usize)>,
+    /// 其他语言的名称，按长度降序排列
+    localized_names: Vec<LocalizedTerm>,
+    /// 不带声调的拼音，按长度降序排列
+    pinyin_names: Vec<PinyinTerm>,
+    alpha3: HashMap<String, usize>,
+    alpha2: HashMap<String, usize>,
+    /// 额外的前缀边界模式
+    prefix_patterns: Vec<String>,
+    /// 额外的后缀边界模式
+    suffix_patterns: Vec<String>,
+    regions: Vec<RegionInfo>,
+    /// 地区名称和别称，按长度降序排列
+    region_terms: Vec<PlaceTerm>,
+    subdivisions: Vec<SubdivisionInfo>,
+    /// 行政区所属国家在索引中的位置
+    subdivision_countries: Vec<Option<usize>>,
+    /// 行政区名称和别称，按长度降序排列
+    subdivision_terms: Vec<PlaceTerm>,
+    /// 含字母的ISO 3166-2代码（如 "US-CA"）
+    subdivision_codes: HashMap<String, usize>,
+    cities: Vec<CityInfo>,
+    /// 城市所属国家在索引中的位置
+    city_countries: Vec<Option<usize>>,
+    /// 城市所属行政区在索引中的位置
+    city_subdivisions: Vec<Option<usize>>,
+    /// 城市名称和别称，按长度降序排列
+    city_terms: Vec<PlaceTerm>,
+    airports: Vec<AirportInfo>,
+    /// 大写的IATA代码
+    airport_codes: HashMap<String, usize>,
+    /// 机场所属国家在索引中的位置
+    airport_countries: Vec<Option<usize>>,
+    /// 机场所在城市在索引中的位置
+    airport_cities: Vec<Option<usize>>,
+    reserved: Vec<ReservedCodeInfo>,
+    /// 大写的保留代码（alpha-2和alpha-3）
+    reserved_codes: HashMap<String, usize>,
+    /// 保留代码对应的国家在索引中的位置
+    reserved_countries: Vec<Option<usize>>,
+}
+
+impl CountryIndex {
+    /// 从配置构建索引
+    pub fn new(configuration: &Configuration) -> Self {
+        let mut countries = configuration.get_countries().to_vec();
+        let country_count = countries.len();
+        let mut abbreviations = Vec::new();
+        let mut english_names = Vec::new();
+        let mut localized_names = Vec::new();
+        let mut pinyin_names = Vec::new();
+        let mut alpha3 = HashMap::new();
+        let mut alpha2 = HashMap::new();
+
+        for (i, country) in countries.iter().enumerate() {
+            for abbr in &country.abbreviations {
+                if !abbr.is_empty() {
+                    abbreviations.push((abbr.to_lowercase(), i));
+                }
+            }
+            english_names.push((country.name_en.to_lowercase(), i));
+            // 只使用简体中文名称，繁体名称（如 "盧安達"）的拼音与简体不同，容易与英文单词冲突
+            let simplified = std::iter::once(&country.name_zh_cn)
+                .chain(&country.abbreviations)
+                .filter(|name| !is_traditional(name, country));
+            for name in simplified {
+                for term in pinyin_terms(configuration, name, i) {
+                    if !pinyin_names.iter().any(|t: &PinyinTerm| t.text == term.text && t.country == i) {
+                        pinyin_names.push(term);
+                    }
                 }
             }
+            for names in country.names.values() {
+                localized_names.extend(names.iter().filter_map(|name| LocalizedTerm::new(name.trim(), i)));
+            }
+            alpha3.entry(country.alpha3.to_uppercase()).or_insert(i);
+            alpha2.entry(country.alpha2.to_uppercase()).or_insert(i);
+        }
+
+        localized_names.sort_by_key(|term| std::cmp::Reverse(term.text.chars().count()));
+        pinyin_names.sort_by_key(|term| std::cmp::Reverse(term.text.len()));
+
+        let regions = configuration.get_regions().to_vec();
+        let mut region_terms = Vec::new();
+        for (i, region) in regions.iter().enumerate() {
+            let names = [&region.name_en, &region.name_zh_cn, &region.name_zh_tw];
+            PlaceTerm::collect(&mut region_terms, i, names.into_iter().chain(&region.aliases));
+        }
+        region_terms.sort_by_key(|term| std::cmp::Reverse(term.text.chars().count()));
+
+        let subdivisions = configuration.get_subdivisions().to_vec();
+        let mut subdivision_countries = Vec::with_capacity(subdivisions.len());
+        let mut subdivision_terms = Vec::new();
+        let mut subdivision_codes = HashMap::new();
+        for (i, subdivision) in subdivisions.iter().enumerate() {
+            subdivision_countries.push(alpha2.get(&subdivision.alpha2().to_uppercase()).copied());
+
+            let names = [&subdivision.name_en, &subdivision.name_zh_cn, &subdivision.name_zh_tw];
+            PlaceTerm::collect(&mut subdivision_terms, i, names.into_iter().chain(&subdivision.aliases));
+
+            let suffix = subdivision.code.get(3..).unwrap_or_default();
+            if suffix.chars().any(|c| c.is_ascii_alphabetic()) {
+                subdivision_codes.entry(subdivision.code.to_uppercase()).or_insert(i);
+            }
         }
-        
-        // 检查 alpha2 代码
-        if text.to_uppercase().contains(&country.alpha2) {
-            let pattern = country.alpha2.to_string();
-            if let Some(pos) = text.to_uppercase().find(&pattern) {
-                let start = pos;
-                let end = pos + pattern.len();
-                
-                // 简化的边界检查
-                let prev_valid = start == 0 || is_boundary_char(text.chars().nth(start-1).unwrap_or(' '));
-                let next_valid = end >= text.len() || is_boundary_char(text.chars().nth(end).unwrap_or(' ')) || text.chars().nth(end).unwrap_or(' ').is_numeric();
-                
-                if prev_valid && next_valid {
-                    return Ok(country.clone());
+        subdivision_terms.sort_by_key(|term| std::cmp::Reverse(term.text.chars().count()));
+
+        let cities = configuration.get_cities().to_vec();
+        let mut city_countries = Vec::with_capacity(cities.len());
+        let mut city_subdivisions = Vec::with_capacity(cities.len());
+        let mut city_terms = Vec::new();
+        for (i, city) in cities.iter().enumerate() {
+            city_countries.push(alpha2.get(&city.country.to_uppercase()).copied());
+            city_subdivisions.push(city.subdivision.as_ref().and_then(|code| {
+                subdivisions.iter().position(|subdivision| subdivision.code.eq_ignore_ascii_case(code))
+            }));
+
+            let names = [&city.name_en, &city.name_zh_cn, &city.name_zh_tw];
+            PlaceTerm::collect(&mut city_terms, i, names.into_iter().chain(&city.aliases));
+        }
+        city_terms.sort_by_key(|term| std::cmp::Reverse(term.text.chars().count()));
+
+        let airports = configuration.get_airports().to_vec();
+        let mut airport_codes = HashMap::new();
+        let mut airport_countries = Vec::with_capacity(airports.len());
+        let mut airport_cities = Vec::with_capacity(airports.len());
+        for (i, airport) in airports.iter().enumerate() {
+            airport_codes.entry(airport.code.to_uppercase()).or_insert(i);
+            airport_countries.push(alpha2.get(&airport.country.to_uppercase()).copied());
+            airport_cities.push(airport.city.as_ref().and_then(|name| {
+                cities.iter().position(|city| city.name_en.eq_ignore_ascii_case(name))
+            }));
+        }
+
+        let reserved = configuration.get_reserved_codes().to_vec();
+        let mut reserved_codes = HashMap::new();
+        let mut reserved_countries = Vec::with_capacity(reserved.len());
+        for (i, code) in reserved.iter().enumerate() {
+            for code in std::iter::once(&code.code).chain(&code.alpha3) {
+                reserved_codes.entry(code.to_uppercase()).or_insert(i);
+            }
+
+            reserved_countries.push(match code.successors.first() {
+                Some(successor) => alpha2.get(&successor.to_uppercase()).copied(),
+                None => {
+                    countries.push(code.to_country_info());
+                    Some(countries.len() - 1)
                 }
+            });
+        }
+
+        Self {
+            countries,
+            country_count,
+            abbreviations,
+            english_names,
+            localized_names,
+            pinyin_names,
+            alpha3,
+            alpha2,
+            prefix_patterns: configuration.get_patterns().prefix_patterns.clone(),
+            suffix_patterns: configuration.get_patterns().suffix_patterns.clone(),
+            regions,
+            region_terms,
+            subdivisions,
+            subdivision_countries,
+            subdivision_terms,
+            subdivision_codes,
+            cities,
+            city_countries,
+            city_subdivisions,
+            city_terms,
+            airports,
+            airport_codes,
+            airport_countries,
+            airport_cities,
+            reserved,
+            reserved_codes,
+            reserved_countries,
+        }
+    }
+
+    /// 内置数据集的共享索引
+    pub fn embedded() -> Result<Arc<CountryIndex>, ParseError> {
+        static EMBEDDED: OnceLock<Result<Arc<CountryIndex>, ParseError>> = OnceLock::new();
+
+        EMBEDDED
+            .get_or_init(|| {
+                Configuration::load()
+                    .map(|configuration| Arc::new(CountryIndex::new(&configuration)))
+                    .map_err(|e| ParseError::config_error(&format!("配置加载失败: {}", e)))
+            })
+            .clone()
+    }
+
+    /// 获取索引中的国家信息
+    pub fn country(&self, index: usize) -> &CountryInfo {
+        &self.countries[index]
+    }
+
+    /// 按alpha-2代码查找国家（不区分大小写）
+    pub fn country_by_alpha2(&self, code: &str) -> Option<&CountryInfo> {
+        self.alpha2.get(&code.to_uppercase()).map(|&i| self.country(i))
+    }
+
+    /// 启用 [`ParserConfig::resolve_sovereign`] 时，获取属地的主权国家
+    pub fn sovereign_of(&self, country: &CountryInfo, config: &ParserConfig) -> Option<&CountryInfo> {
+        let code = country.sovereign.as_deref().filter(|_| config.resolve_sovereign)?;
+        self.country_by_alpha2(code)
+    }
+
+    /// 启用 [`ParserConfig::resolve_sovereign`] 时，获取归并到该国家的属地
+    pub fn territories_of<'a>(
+        &'a self,
+        country: &'a CountryInfo,
+        config: &'a ParserConfig,
+    ) -> impl Iterator<Item = &'a CountryInfo> + 'a {
+        self.countries[..self.country_count]
+            .iter()
+            .filter(move |territory| self.sovereign_of(territory, config).is_some_and(|s| s.alpha2 == country.alpha2))
+    }
+
+    /// 获取以指定国家为主要继承国的保留代码，非国家实体返回自身的代码
+    pub fn reserved_codes_of<'a>(&'a self, country: &'a CountryInfo) -> impl Iterator<Item = &'a ReservedCodeInfo> + 'a {
+        self.reserved.iter().zip(&self.reserved_countries).filter_map(move |(code, &owner)| {
+            owner.filter(|&i| self.countries[i].alpha2 == country.alpha2).map(|_| code)
+        })
+    }
+
+    /// 查找大写的保留代码，`historical` 为假时只查找特别保留代码
+    fn reserved_code(&self, token: &str, historical: bool) -> Option<usize> {
+        if !token.bytes().all(|b| b.is_ascii_uppercase()) {
+            return None;
+        }
+
+        let &i = self.reserved_codes.get(token)?;
+        (historical || self.reserved[i].kind == ReservedKind::ExceptionallyReserved).then_some(i)
+    }
+
+    /// 额外的前缀边界模式
+    pub fn prefix_patterns(&self) -> &[String] {
+        &self.prefix_patterns
+    }
+
+    /// 额外的后缀边界模式
+    pub fn suffix_patterns(&self) -> &[String] {
+        &self.suffix_patterns
+    }
+
+    /// 获取索引中的地区信息
+    pub fn region(&self, index: usize) -> &RegionInfo {
+        &self.regions[index]
+    }
+
+    /// 将阶段匹配结果转换为详细结果
+    ///
+    /// 启用 [`ParserConfig::resolve_sovereign`] 时，属地会被替换为主权国家。
+    pub fn to_match(&self, stage: MatchStage, m: StageMatch, config: &ParserConfig) -> CountryMatch {
+        let country = self.country(m.country);
+        let sovereign = self.sovereign_of(country, config);
+
+        CountryMatch {
+            country: sovereign.unwrap_or(country).clone(),
+            territory: sovereign.map(|_| country.clone()),
+            stage,
+            span: m.start..m.end,
+            subdivision: m.subdivision.map(|i| self.subdivisions[i].clone()),
+            city: m.city.map(|i| self.cities[i].clone()),
+            airport: m.airport.map(|i| self.airports[i].clone()),
+            reserved: m.reserved.map(|i| self.reserved[i].clone()),
+            carrier: m.carrier,
+        }
+    }
+
+    /// 查找文本中最长的地区名称，返回地区在索引中的位置
+    pub fn find_region(&self, text: &str, config: &ParserConfig) -> Option<usize> {
+        let context = self.context(text, config);
+
+        self.region_terms
+            .iter()
+            .find(|term| context.find_term(&term.text, term.word).is_some())
+            .map(|term| term.target)
+    }
+
+    /// 按启用的阶段依次匹配，返回第一个命中的阶段及结果
+    ///
+    /// 命中国家后，还会在文本中查找该国家的机场、行政区和城市（对应阶段需要启用）。
+    pub fn find(&self, text: &str, config: &ParserConfig) -> Option<(MatchStage, StageMatch)> {
+        let context = self.context(text, config);
+
+        let (stage, mut m) = config
+            .stages
+            .iter()
+            .find_map(|&stage| self.find_stage(&context, stage).map(|m| (stage, m)))?;
+
+        m.carrier = context.carriers.first().map(|c| c.value);
+
+        if m.airport.is_none() {
+            m.airport = self.find_airport(&context, Some(m.country)).and_then(|a| a.airport);
+            m.city = m.city.or_else(|| m.airport.and_then(|airport| self.airport_cities[airport]));
+        }
+
+        if m.subdivision.is_none() && config.stages.contains(&MatchStage::Subdivision) {
+            m.subdivision = self
+                .find_subdivision(&context, Some(m.country))
+                .and_then(|s| s.subdivision);
+        }
+
+        if m.city.is_none() && config.stages.contains(&MatchStage::City) {
+            m.city = self.find_city(&context, Some(m.country)).and_then(|c| c.city);
+        }
+
+        if m.subdivision.is_none() {
+            m.subdivision = m.city.and_then(|city| self.city_subdivisions[city]);
+        }
+
+        Some((stage, m))
+    }
+
+    /// 创建解析上下文，记录文本中所有地名和IATA代码的范围
+    ///
+    /// 按alpha-3代码解析的冲突代码不记录为IATA代码。
+    fn context<'a>(&self, text: &'a str, config: &ParserConfig) -> Context<'a> {
+        let folded = FoldedText::new(text);
+        let prefer_iata = config.iata_conflict == IataConflict::PreferIata;
+
+        let mut airports = Vec::new();
+        if config.stages.contains(&MatchStage::Iata) {
+            airports.extend(iata_tokens(text).filter_map(|(start, end)| {
+                let code = &text[start..end];
+                let &i = self.airport_codes.get(code)?;
+                (prefer_iata || !self.alpha3.contains_key(code)).then_some((i, start, end))
+            }));
+        }
+
+        let mut places = Vec::new();
+        let terms = self.region_terms.iter().chain(&self.subdivision_terms).chain(&self.city_terms);
+        for term in terms {
+            places.extend(
+                folded
+                    .find_all(&term.text)
+                    .filter(|&(start, end)| !term.word || is_word_bounded(text, start, end)),
+            );
+        }
+
+        places.extend(airports.iter().map(|&(_, start, end)| (start, end)));
+
+        // 保留的alpha-3代码（如 "SCG"）内部的alpha-2代码不是国家
+        let historical_reserved = config.historical_reserved;
+        if config.stages.contains(&MatchStage::Reserved) {
+            places.extend(ascii_words(text).filter(|&(start, end)| {
+                end - start == 3 && self.reserved_code(&text[start..end], historical_reserved).is_some()
+            }));
+        }
+
+        let carriers = find_carriers(text, &folded);
+        Context { text, folded, places, airports, prefer_iata, historical_reserved, carriers }
+    }
+
+    fn find_stage(&self, context: &Context, stage: MatchStage) -> Option<StageMatch> {
+        match stage {
+            MatchStage::Flag => self.find_flag(context),
+            MatchStage::Abbreviation => find_folded_terms(context, &self.abbreviations, true),
+            MatchStage::TraditionalChinese => {
+                self.find_names(context, |country| &country.name_zh_tw)
+            }
+            MatchStage::SimplifiedChinese => {
+                self.find_names(context, |country| &country.name_zh_cn)
             }
+            MatchStage::English => find_folded_terms(context, &self.english_names, false),
+            MatchStage::Localized => self.find_localized(context),
+            MatchStage::Pinyin => self.find_pinyin(context),
+            MatchStage::Alpha3 => self.find_code(context, 3, &self.alpha3, false),
+            MatchStage::Alpha2 => self.find_code(context, 2, &self.alpha2, false),
+            MatchStage::Reserved => self.find_reserved(context),
+            MatchStage::Iata => self.find_airport(context, None),
+            MatchStage::CallingCode => self.find_calling_code(context),
+            MatchStage::Subdivision => self.find_subdivision(context, None),
+            MatchStage::City => self.find_city(context, None),
         }
     }
-    
-    Err(ParseError::not_found(text))
-}
 
-/// 解析别名和简称
-fn parse_abbreviations(
-    text: &str,
-    config_data: &Configuration,
-) -> Result<CountryInfo, ParseError> {
-    let processed_text = text.to_lowercase();
-    
-    for country in config_data.get_countries() {
-        for abbr in &country.abbreviations {
-            if processed_text.contains(&abbr.to_lowercase()) {
-                return Ok(country.clone());
+    /// 按国家顺序查找原样出现的名称
+    fn find_names<F>(&self, context: &Context, name: F) -> Option<StageMatch>
+    where
+        F: Fn(&CountryInfo) -> &String,
+    {
+        self.countries[..self.country_count].iter().enumerate().find_map(|(i, country)| {
+            let name = name(country);
+            if name.is_empty() {
+                return None;
+            }
+            context
+                .text
+                .match_indices(name.as_str())
+                .map(|(start, _)| (start, start + name.len()))
+                .find(|&(start, end)| !context.is_shadowed(start, end))
+                .map(|(start, end)| StageMatch::new(i, start, end))
+        })
+    }
+
+    /// 按最长匹配查找其他语言的名称，跳过文本中没有出现的文字
+    fn find_localized(&self, context: &Context) -> Option<StageMatch> {
+        let mut scripts = Vec::new();
+        for script in context.text.chars().map(Script::of) {
+            if !scripts.contains(&script) {
+                scripts.push(script);
             }
         }
+
+        self.localized_names
+            .iter()
+            .filter(|term| scripts.contains(&term.first))
+            .find_map(|term| {
+                context
+                    .folded
+                    .find_all(&term.text)
+                    .find(|&(start, end)| {
+                        term.is_bounded(context.text, start, end) && !context.is_shadowed(start, end)
+                    })
+                    .map(|(start, end)| StageMatch::new(term.country, start, end))
+            })
     }
-    
-    Err(ParseError::not_found(text))
-}
 
-/// 解析繁体中文名称
-fn parse_traditional_chinese_names(
-    text: &str,
-    config_data: &Configuration,
-) -> Result<CountryInfo, ParseError> {
-    for country in config_data.get_countries() {
-        if text.contains(&country.name_zh_tw) {
-            return Ok(country.clone());
+    /// 查找完整单词的拼音，拼音之间可以有空格
+    fn find_pinyin(&self, context: &Context) -> Option<StageMatch> {
+        if !context.text.chars().any(|c| c.is_ascii_alphabetic()) {
+            return None;
         }
+
+        let romanized = FoldedText::romanized(context.text);
+        self.pinyin_names.iter().find_map(|term| {
+            romanized
+                .find_all(&term.text)
+                .find(|&(start, end)| {
+                    is_word_bounded(context.text, start, end)
+                        && term.is_syllabified(context.text, start, end)
+                        && !context.is_shadowed(start, end)
+                })
+                .map(|(start, end)| StageMatch::new(term.country, start, end))
+        })
     }
-    
-    Err(ParseError::not_found(text))
-}
 
-/// 解析简体中文名称
-fn parse_simplified_chinese_names(
-    text: &str,
-    config_data: &Configuration,
-) -> Result<CountryInfo, ParseError> {
-    for country in config_data.get_countries() {
-        if text.contains(&country.name_zh_cn) {
-            return Ok(country.clone());
+    /// 查找国旗emoji，连续的区域指示符从头开始两两配对
+    fn find_flag(&self, context: &Context) -> Option<StageMatch> {
+        let mut pending: Option<(usize, char)> = None;
+
+        for (start, c) in context.text.char_indices() {
+            let Some(letter) = regional_indicator_letter(c) else {
+                pending = None;
+                continue;
+            };
+            let Some((first_start, first)) = pending.take() else {
+                pending = Some((start, letter));
+                continue;
+            };
+
+            let code: String = [first, letter].iter().collect();
+            if let Some(&country) = self.alpha2.get(&code) {
+                return Some(StageMatch::new(country, first_start, start + c.len_utf8()));
+            }
         }
+
+        None
+    }
+
+    /// 查找保留代码，先查找alpha-3形式再查找alpha-2形式
+    fn find_reserved(&self, context: &Context) -> Option<StageMatch> {
+        [3, 2].into_iter().find_map(|len| {
+            let m = self.find_code(context, len, &self.reserved_codes, true)?;
+            let country = self.reserved_countries[m.country]?;
+            Some(StageMatch {
+                reserved: Some(m.country),
+                ..StageMatch::new(country, m.start, m.end)
+            })
+        })
     }
-    
-    Err(ParseError::not_found(text))
+
+    /// 扫描文本中的ISO代码，允许代码后面跟着数字
+    ///
+    /// `reserved` 为真时查找保留代码：代码需要大写、后面不能紧跟字母（后缀边界模式除外），且代码类型需要启用。
+    fn find_code(
+        &self,
+        context: &Context,
+        len: usize,
+        codes: &HashMap<String, usize>,
+        reserved: bool,
+    ) -> Option<StageMatch> {
+        let text = context.text;
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+
+        for i in 0..chars.len().saturating_sub(len - 1) {
+            let window = &chars[i..i + len];
+            if !window.iter().all(|(_, c)| c.is_ascii_alphabetic()) {
+                continue;
+            }
+
+            let code: String = window.iter().map(|(_, c)| c.to_ascii_uppercase()).collect();
+            let Some(&country) = codes.get(&code) else {
+                continue;
+            };
+
+            // 检查是否是有效的ISO代码位置
+            let start = chars[i].0;
+            if i > 0 && !self.is_left_boundary(&text[..start]) {
+                continue;
+            }
+
+            let end = chars.get(i + len).map_or(text.len(), |(pos, _)| *pos);
+            if reserved
+                && (!self.is_right_boundary(&text[end..])
+                    || self.reserved_code(&text[start..end], context.historical_reserved).is_none())
+            {
+                continue;
+            }
+            if context.is_shadowed(start, end) || context.is_preferred_airport(start, end) {
+                continue;
+            }
+
+            return Some(StageMatch::new(country, start, end));
+        }
+
+        None
+    }
+
+    /// 查找行政区，`country` 不为空时只查找该国家的行政区
+    ///
+    /// 先查找 "US-CA" 形式的代码，再按最长匹配查找名称。纯数字的代码（如 "JP-13"）
+    /// 与节点编号无法区分，不参与代码匹配。
+    fn find_subdivision(&self, context: &Context, country: Option<usize>) -> Option<StageMatch> {
+        let owners = &self.subdivision_countries;
+
+        let code_match = code_tokens(context.text).find_map(|(start, end)| {
+            let &i = self.subdivision_codes.get(&context.text[start..end].to_uppercase())?;
+            belongs(owners[i], country).then_some((i, start, end))
+        });
+
+        let (i, start, end) = code_match
+            .or_else(|| find_place(context, &self.subdivision_terms, owners, country))?;
+
+        Some(StageMatch {
+            subdivision: Some(i),
+            ..StageMatch::new(owners[i]?, start, end)
+        })
+    }
+
+    /// 查找IATA代码，`country` 不为空时只查找该国家的机场
+    fn find_airport(&self, context: &Context, country: Option<usize>) -> Option<StageMatch> {
+        let owners = &self.airport_countries;
+        let &(i, start, end) = context
+            .airports
+            .iter()
+            .find(|&&(i, start, end)| belongs(owners[i], country) && !context.is_shadowed(start, end))?;
+
+        let city = self.airport_cities[i];
+        Some(StageMatch {
+            subdivision: city.and_then(|city| self.city_subdivisions[city]),
+            city,
+            airport: Some(i),
+            ..StageMatch::new(owners[i]?, start, end)
+        })
+    }
+
+    /// 查找 "+NNN" 形式的电话区号
+    ///
+    /// 共用区号时使用紧跟在区号后面的alpha-2代码区分（如 "+1-US"），无法区分时跳过。
+    fn find_calling_code(&self, context: &Context) -> Option<StageMatch> {
+        let text = context.text;
+
+        calling_code_tokens(text).find_map(|(start, end)| {
+            let owners: Vec<usize> = calling_code_matches(&self.countries, &text[start + 1..end])
+                .into_iter()
+                .filter_map(|(country, exact)| exact.then_some(country))
+                .collect();
+
+            match owners[..] {
+                [country] => Some(StageMatch::new(country, start, end)),
+                [] => None,
+                _ => {
+                    let rest = text[end..].trim_start_matches(['-', '_', ' ']);
+                    let code = rest.get(..2).filter(|_| is_word_bounded(rest, 0, 2))?;
+                    let &country = self.alpha2.get(&code.to_ascii_uppercase())?;
+                    let code_end = text.len() - rest.len() + 2;
+                    owners.contains(&country).then(|| StageMatch::new(country, start, code_end))
+                }
+            }
+        })
+    }
+
+    /// 查找城市，`country` 不为空时只查找该国家的城市
+    fn find_city(&self, context: &Context, country: Option<usize>) -> Option<StageMatch> {
+        let owners = &self.city_countries;
+        let (i, start, end) = find_place(context, &self.city_terms, owners, country)?;
+
+        Some(StageMatch {
+            subdivision: self.city_subdivisions[i],
+            city: Some(i),
+            ..StageMatch::new(owners[i]?, start, end)
+        })
+    }
+
+    /// 检查代码前面的文本是否构成有效边界
+    fn is_left_boundary(&self, before: &str) -> bool {
+        let Some(prev) = before.chars().next_back() else {
+            return true;
+        };
+
+        is_boundary_char(prev)
+            || prev.is_numeric()
+            || self.prefix_patterns.iter().any(|p| !p.is_empty() && before.ends_with(p.as_str()))
+    }
+
+    /// 检查完整单词匹配的代码后面的文本是否构成有效边界：不紧跟字母，或紧跟后缀边界模式（如 "UKVip"）
+    fn is_right_boundary(&self, after: &str) -> bool {
+        !after.starts_with(|c: char| c.is_ascii_alphabetic())
+            || self.suffix_patterns.iter().any(|p| !p.is_empty() && after.starts_with(p.as_str()))
+    }
+}
+
+/// 与常见英文单词或人名相同的拼音，不作为拼音词条
+const PINYIN_BLOCKLIST: &[&str] = &["aman", "angela", "balin"];
+
+/// 检查名称是否使用了只出现在繁体名称中的字
+fn is_traditional(name: &str, country: &CountryInfo) -> bool {
+    name.chars().any(|c| country.name_zh_tw.contains(c) && !country.name_zh_cn.contains(c))
+}
+
+/// 生成中文名称的拼音词条，至少两个音节；"ü" 同时生成 "v" 和 "u" 两种写法
+fn pinyin_terms(configuration: &Configuration, name: &str, country: usize) -> Vec<PinyinTerm> {
+    let Some(syllables) = configuration.pinyin_of(name.trim()) else {
+        return Vec::new();
+    };
+    if syllables.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut syllable_ends = Vec::with_capacity(syllables.len());
+    for syllable in &syllables {
+        syllable_ends.push(syllable_ends.last().copied().unwrap_or(0) + syllable.chars().count());
+    }
+
+    let term: String = syllables.concat().chars().map(strip_tone).collect();
+    let mut texts = vec![term.clone()];
+    if term.contains('v') {
+        texts.push(term.replace('v', "u"));
+    }
+    texts
+        .into_iter()
+        .filter(|text| !PINYIN_BLOCKLIST.contains(&text.as_str()))
+        .map(|text| PinyinTerm { text, country, syllable_ends: syllable_ends.clone() })
+        .collect()
+}
+
+/// 去掉拼音字母的声调，"ü" 写作 "v"
+fn strip_tone(c: char) -> char {
+    match c {
+        'ā' | 'á' | 'ǎ' | 'à' => 'a',
+        'ē' | 'é' | 'ě' | 'è' => 'e',
+        'ī' | 'í' | 'ǐ' | 'ì' => 'i',
+        'ō' | 'ó' | 'ǒ' | 'ò' => 'o',
+        'ū' | 'ú' | 'ǔ' | 'ù' => 'u',
+        'ü' | 'ǖ' | 'ǘ' | 'ǚ' | 'ǜ' => 'v',
+        'ń' | 'ň' | 'ǹ' => 'n',
+        _ => c,
+    }
+}
+
+/// 在小写化文本中按顺序查找词条
+///
+/// `ascii_words` 为真时，纯ASCII的词条（如 "UK"）需要完整单词匹配。
+fn find_folded_terms(context: &Context, terms: &[(String, usize)], ascii_words: bool) -> Option<StageMatch> {
+    terms.iter().find_map(|(term, country)| {
+        context
+            .find_term(term, ascii_words && term.is_ascii())
+            .map(|(start, end)| StageMatch::new(*country, start, end))
+    })
+}
+
+/// 检查地名是否属于指定国家，`country` 为空时只要求地名有所属国家
+fn belongs(owner: Option<usize>, country: Option<usize>) -> bool {
+    owner.is_some() && (country.is_none() || country == owner)
+}
+
+/// 按最长匹配查找属于指定国家的地名，返回地名位置和字节范围
+fn find_place(
+    context: &Context,
+    terms: &[PlaceTerm],
+    owners: &[Option<usize>],
+    country: Option<usize>,
+) -> Option<(usize, usize, usize)> {
+    terms
+        .iter()
+        .filter(|term| belongs(owners[term.target], country))
+        .find_map(|term| {
+            context
+                .find_term(&term.text, term.word)
+                .map(|(start, end)| (term.target, start, end))
+        })
+}
+
+/// 区域指示符（U+1F1E6..U+1F1FF）对应的大写字母
+fn regional_indicator_letter(c: char) -> Option<char> {
+    let offset = (c as u32).checked_sub(0x1F1E6).filter(|&offset| offset < 26)?;
+    char::from_u32('A' as u32 + offset)
+}
+
+/// 查找由ASCII字母组成的完整单词，返回字节范围
+fn ascii_words(text: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let bytes = text.as_bytes();
+
+    (0..bytes.len()).filter_map(move |start| {
+        if !bytes[start].is_ascii_alphabetic() || (start > 0 && bytes[start - 1].is_ascii_alphabetic()) {
+            return None;
+        }
+
+        let len = bytes[start..].iter().take_while(|b| b.is_ascii_alphabetic()).count();
+        Some((start, start + len))
+    })
+}
+
+/// 查找 "+" 后跟数字的候选电话区号，"+" 前面不能是字母或数字
+fn calling_code_tokens(text: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let bytes = text.as_bytes();
+
+    (0..bytes.len()).filter_map(move |start| {
+        if bytes[start] != b'+' || (start > 0 && bytes[start - 1].is_ascii_alphanumeric()) {
+            return None;
+        }
+
+        let len = bytes[start + 1..].iter().take_while(|b| b.is_ascii_digit()).count();
+        (len > 0).then_some((start, start + 1 + len))
+    })
+}
+
+/// 查找由3个大写ASCII字母组成的候选IATA代码，两侧不能是字母
+fn iata_tokens(text: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let bytes = text.as_bytes();
+
+    (0..bytes.len().saturating_sub(2)).filter_map(move |start| {
+        let end = start + 3;
+        let upper = bytes[start..end].iter().all(u8::is_ascii_uppercase);
+        (upper && is_word_bounded(text, start, end)).then_some((start, end))
+    })
+}
+
+/// 查找 "XX-YYY" 形式的行政区代码候选，返回字节范围
+fn code_tokens(text: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let bytes = text.as_bytes();
+
+    (0..bytes.len()).filter_map(move |start| {
+        if start > 0 && bytes[start - 1].is_ascii_alphanumeric() {
+            return None;
+        }
+        if bytes.len() < start + 4
+            || !bytes[start].is_ascii_alphabetic()
+            || !bytes[start + 1].is_ascii_alphabetic()
+            || bytes[start + 2] != b'-'
+        {
+            return None;
+        }
+
+        let len = bytes[start + 3..].iter().take_while(|b| b.is_ascii_alphanumeric()).count();
+        (1..=3).contains(&len).then_some((start, start + 3 + len))
+    })
+}
+
+/// 检查范围两侧是否不是ASCII字母
+pub(crate) fn is_word_bounded(text: &str, start: usize, end: usize) -> bool {
+    let prev = text[..start].chars().next_back();
+    let next = text[end..].chars().next();
+
+    !prev.is_some_and(|c| c.is_ascii_alphabetic()) && !next.is_some_and(|c| c.is_ascii_alphabetic())
 }
 
-/// 解析英文名称
-fn parse_english_names(
+/// 主要的解析函数
+pub fn parse_country_code(text: &str) -> Result<CountryInfo, ParseError> {
+    parse_country_code_with_config(text, &ParserConfig::default())
+}
+
+/// 使用配置的解析函数
+pub fn parse_country_code_with_config(
     text: &str,
-    config_data: &Configuration,
+    config: &ParserConfig,
 ) -> Result<CountryInfo, ParseError> {
-    let processed_text = text.to_lowercase();
-    
-    for country in config_data.get_countries() {
-        if processed_text.contains(&country.name_en.to_lowercase()) {
-            return Ok(country.clone());
-        }
+    let index = CountryIndex::embedded()?;
+    parse_with_index(text, config, &index).map(|m| m.country)
+}
+
+/// 解析文本，返回包含匹配阶段、位置和行政区的详细结果
+pub fn parse_detailed(text: &str) -> Result<CountryMatch, ParseError> {
+    let index = CountryIndex::embedded()?;
+    parse_with_index(text, &ParserConfig::default(), &index)
+}
+
+/// 使用指定索引解析文本
+pub(crate) fn parse_with_index(
+    text: &str,
+    config: &ParserConfig,
+    index: &CountryIndex,
+) -> Result<CountryMatch, ParseError> {
+    validate_input(text)?;
+
+    // 按照指定优先级进行匹配：国旗 > 别名和简称 > 繁体中文名 > 简体中文名 > 英文名 > 其他语言名称 > 拼音 > 保留代码 > alpha3 > alpha2 > IATA > 电话区号 > 行政区 > 城市
+    index
+        .find(text, config)
+        .map(|(stage, m)| index.to_match(stage, m, config))
+        .ok_or_else(|| ParseError::not_found(text))
+}
+
+/// 解析文本中的国家，文本中只有地区时返回地区
+pub fn parse_location(text: &str) -> Result<Location, ParseError> {
+    let index = CountryIndex::embedded()?;
+    parse_location_with_index(text, &ParserConfig::default(), &index)
+}
+
+/// 使用指定索引解析国家或地区
+///
+/// 地区名称内部的代码（如 "North America" 中的 "NO"）不会被当作国家。
+pub(crate) fn parse_location_with_index(
+    text: &str,
+    config: &ParserConfig,
+    index: &CountryIndex,
+) -> Result<Location, ParseError> {
+    validate_input(text)?;
+
+    if let Some((stage, m)) = index.find(text, config) {
+        return Ok(Location::Country(Box::new(index.to_match(stage, m, config))));
     }
-    
-    Err(ParseError::not_found(text))
+
+    index
+        .find_region(text, config)
+        .map(|region| Location::Region(index.region(region).clone()))
+        .ok_or_else(|| ParseError::not_found(text))
 }
 
+/// 输入验证
+pub(crate) fn validate_input(text: &str) -> Result<(), ParseError> {
+    if text.trim().is_empty() {
+        return Err(ParseError::invalid_input("输入文本为空"));
+    }
 
+    if text.len() > 1024 {
+        return Err(ParseError::invalid_input("输入文本过长"));
+    }
+
+    Ok(())
+}
 
-// 不再需要pattern_matching函数，已被新的匹配算法替代
+/// 边界字符的正则字符类内容，与 [`is_boundary_char`] 一致
+pub(crate) const BOUNDARY_CLASS: &str = r"\s\p{Z}@【\[#\]】";
 
 /// 检查字符是否是边界字符
 fn is_boundary_char(c: char) -> bool {
-    c.is_whitespace() || c == '@' || c == '【' || c == '[' || c == '#' || 
+    c.is_whitespace() || c == '@' || c == '【' || c == '[' || c == '#' ||
     c == ']' || c == '】' || c == ' ' || c == '\t' || c == '\n'
-}
\ No newline at end of file
+}
@@ -0,0 +1,207 @@
+//! 中转节点
+//!
+//! 识别 "香港→美国"、"HK-US 中转"、"广港IPLC-日本"、"沪日专线" 等描述线路的名称，
+//! 分别返回入口和出口位置。中转节点的主要国家是出口国家，而不是名称中第一个出现的国家。
+
+use crate::config::CountryInfo;
+use crate::error::ParseError;
+use crate::parser::{CountryMatch, Script};
+use crate::Parser;
+
+/// 线路感知的解析结果
+#[derive(Debug, Clone)]
+pub struct RelayMatch {
+    /// 入口位置，不是中转节点时为 `None`
+    pub entry: Option<CountryMatch>,
+    /// 出口位置，不是中转节点时为普通的解析结果
+    pub exit: CountryMatch,
+}
+
+impl RelayMatch {
+    /// 主要国家，即出口国家
+    pub fn country(&self) -> &CountryInfo {
+        &self.exit.country
+    }
+
+    /// 是否是中转节点
+    pub fn is_relay(&self) -> bool {
+        self.entry.is_some()
+    }
+}
+
+/// 线路分隔符，按顺序尝试（"->" 先于 "-"，"中转" 先于 "转"）
+const SEPARATORS: &[&str] = &["->", "=>", "→", "⇒", "➡", "➔", "⟶", "»", "中转", "转", "-", "—", "–"];
+
+/// 表示中转的分隔符，名称中出现时短横线也视为线路分隔符
+const RELAY_SEPARATORS: &[&str] = &["->", "=>", "→", "⇒", "➡", "➔", "⟶", "»", "中转", "转"];
+
+/// 表示专线的词（小写），名称中出现时短横线也视为线路分隔符
+const LINE_HINTS: &[&str] = &["专线", "iplc", "iepl", "relay"];
+
+/// 紧凑写法（如 "沪日专线"）中入口城市或省份的单字简称
+const ENTRY_ABBREVIATIONS: &[(char, &str)] = &[
+    ('沪', "上海"),
+    ('京', "北京"),
+    ('津', "天津"),
+    ('渝', "重庆"),
+    ('广', "广州"),
+    ('深', "深圳"),
+    ('杭', "杭州"),
+    ('粤', "广东"),
+    ('苏', "江苏"),
+    ('浙', "浙江"),
+    ('闽', "福建"),
+    ('鲁', "山东"),
+    ('川', "四川"),
+];
+
+/// 紧凑写法中国家或地区的单字简称
+const COUNTRY_ABBREVIATIONS: &[(char, &str)] = &[
+    ('港', "香港"),
+    ('台', "台湾"),
+    ('日', "日本"),
+    ('韩', "韩国"),
+    ('新', "新加坡"),
+    ('美', "美国"),
+    ('加', "加拿大"),
+    ('英', "英国"),
+    ('法', "法国"),
+    ('德', "德国"),
+    ('荷', "荷兰"),
+    ('俄', "俄罗斯"),
+    ('土', "土耳其"),
+    ('印', "印度"),
+    ('泰', "泰国"),
+    ('越', "越南"),
+    ('菲', "菲律宾"),
+];
+
+/// 线路感知的解析
+///
+/// 名称中有箭头、"中转"、"转" 或专线标记（"专线"、"IPLC"、"IEPL"、"Relay"）时，按分隔符拆分名称，
+/// 第一个位置为入口，最后一个位置为出口；入口和出口是同一国家或不是中转节点时，按普通解析返回。
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::relay::parse_relay;
+///
+/// let result = parse_relay("广港IPLC-日本").unwrap();
+/// assert_eq!(result.country().alpha2, "JP");
+/// assert_eq!(result.entry.unwrap().city.unwrap().name_en, "Guangzhou");
+///
+/// let result = parse_relay("香港 01").unwrap();
+/// assert!(!result.is_relay());
+/// assert_eq!(result.country().alpha2, "HK");
+/// ```
+pub fn parse_relay(text: &str) -> Result<RelayMatch, ParseError> {
+    Parser::new().parse_relay(text)
+}
+
+/// 使用指定解析器进行线路感知的解析
+pub(crate) fn parse_relay_with(text: &str, parser: &Parser) -> Result<RelayMatch, ParseError> {
+    let hops = hops(text, parser);
+    if let [first, .., last] = &hops[..] {
+        if first.country.alpha2 != last.country.alpha2 {
+            return Ok(RelayMatch {
+                entry: Some(first.clone()),
+                exit: last.clone(),
+            });
+        }
+    }
+
+    parser.parse_detailed(text).map(|exit| RelayMatch { entry: None, exit })
+}
+
+/// 按顺序解析线路上的各个位置，名称不描述线路时返回空列表
+fn hops(text: &str, parser: &Parser) -> Vec<CountryMatch> {
+    let lower = text.to_lowercase();
+    let is_line = RELAY_SEPARATORS.iter().any(|separator| text.contains(separator))
+        || LINE_HINTS.iter().any(|hint| lower.contains(hint));
+    if !is_line {
+        return Vec::new();
+    }
+
+    let mut hops = Vec::new();
+    for (offset, segment) in segments(text, parser) {
+        let found = match compact_hops(segment, parser) {
+            Some(compact) => compact,
+            None => parser.parse_detailed(segment).into_iter().collect(),
+        };
+        hops.extend(found.into_iter().map(|mut m| {
+            m.span = m.span.start + offset..m.span.end + offset;
+            m
+        }));
+    }
+    hops
+}
+
+/// 按线路分隔符拆分名称，返回各段的起始位置和内容
+///
+/// 行政区代码（如 "US-CA"）中的短横线不拆分。
+fn segments<'a>(text: &'a str, parser: &Parser) -> Vec<(usize, &'a str)> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+
+    while i < text.len() {
+        let separator = SEPARATORS.iter().find(|separator| text[i..].starts_with(*separator));
+        match separator {
+            Some(separator) if !is_subdivision_dash(text, i, parser) => {
+                segments.push((start, &text[start..i]));
+                i += separator.len();
+                start = i;
+            }
+            _ => i += text[i..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+
+    segments.push((start, &text[start..]));
+    segments.retain(|(_, segment)| !segment.trim().is_empty());
+    segments
+}
+
+/// 检查位置 `i` 的字符是否是行政区代码中的短横线
+fn is_subdivision_dash(text: &str, i: usize, parser: &Parser) -> bool {
+    if !text[i..].starts_with('-') {
+        return false;
+    }
+
+    let start = text[..i].trim_end_matches(|c: char| c.is_ascii_alphanumeric()).len();
+    let after = &text[i + 1..];
+    let end = text.len() - after.trim_start_matches(|c: char| c.is_ascii_alphanumeric()).len();
+    let token = &text[start..end];
+
+    parser
+        .parse_detailed(token)
+        .is_ok_and(|m| m.subdivision.is_some_and(|s| s.code.eq_ignore_ascii_case(token)))
+}
+
+/// 解析段首的紧凑写法（如 "沪日专线"、"广港IPLC"），返回入口和出口两个位置
+///
+/// 第一个字可以是入口城市、省份或国家的简称，第二个字是国家的简称，后面不能紧跟其他汉字（"专线" 除外），
+/// 避免误判 "新加坡"、"日本" 等名称。
+fn compact_hops(segment: &str, parser: &Parser) -> Option<Vec<CountryMatch>> {
+    let trimmed = segment.trim_start();
+    let offset = segment.len() - trimmed.len();
+
+    let mut chars = trimmed.chars();
+    let (first, second) = (chars.next()?, chars.next()?);
+    let rest = chars.as_str();
+    if rest.starts_with(|c: char| Script::of(c) == Script::Han) && !rest.starts_with("专线") {
+        return None;
+    }
+
+    let lookup = |table: &[(char, &'static str)], c: char| table.iter().find(|(abbr, _)| *abbr == c).map(|(_, name)| *name);
+    let entry = lookup(ENTRY_ABBREVIATIONS, first).or_else(|| lookup(COUNTRY_ABBREVIATIONS, first))?;
+    let exit = lookup(COUNTRY_ABBREVIATIONS, second)?;
+
+    let second_start = offset + first.len_utf8();
+    let hop = |name: &str, start: usize, c: char| {
+        parser.parse_detailed(name).ok().map(|mut m| {
+            m.span = start..start + c.len_utf8();
+            m
+        })
+    };
+    Some(vec![hop(entry, offset, first)?, hop(exit, second_start, second)?])
+}
@@ -0,0 +1,372 @@
+//! 节点重命名
+//!
+//! 按模板（如 `"{flag} {alpha2}-{city} {index:02}"`）将节点名称统一为固定格式。
+//! 占位符的值来自解析结果和原名称中的节点属性，`{index}` 是同一国家内的序号；没有值的占位符输出为空，
+//! 并去掉因此多出的空白和分隔符。
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::attributes::{extract_attributes, Attribute};
+use crate::config::{CityInfo, CountryInfo, SubdivisionInfo};
+use crate::error::ParseError;
+use crate::parser::CountryMatch;
+use crate::Parser;
+
+/// 占位符
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Flag,
+    Alpha2,
+    Alpha3,
+    Numeric,
+    NameEn,
+    NameZhCn,
+    NameZhTw,
+    Subdivision,
+    SubdivisionEn,
+    SubdivisionZhCn,
+    SubdivisionZhTw,
+    City,
+    CityZhCn,
+    CityZhTw,
+    Multiplier,
+    Bandwidth,
+    LineType,
+    Carrier,
+    Streaming,
+    Index,
+    Original,
+}
+
+impl Field {
+    fn from_name(name: &str) -> Option<Self> {
+        let field = match name {
+            "flag" => Self::Flag,
+            "alpha2" => Self::Alpha2,
+            "alpha3" => Self::Alpha3,
+            "numeric" => Self::Numeric,
+            "name_en" => Self::NameEn,
+            "name_zh_cn" => Self::NameZhCn,
+            "name_zh_tw" => Self::NameZhTw,
+            "subdivision" => Self::Subdivision,
+            "subdivision_en" => Self::SubdivisionEn,
+            "subdivision_zh_cn" => Self::SubdivisionZhCn,
+            "subdivision_zh_tw" => Self::SubdivisionZhTw,
+            "city" => Self::City,
+            "city_zh_cn" => Self::CityZhCn,
+            "city_zh_tw" => Self::CityZhTw,
+            "multiplier" => Self::Multiplier,
+            "bandwidth" => Self::Bandwidth,
+            "line_type" => Self::LineType,
+            "carrier" => Self::Carrier,
+            "streaming" => Self::Streaming,
+            "index" => Self::Index,
+            "original" => Self::Original,
+            _ => return None,
+        };
+        Some(field)
+    }
+}
+
+/// 模板片段
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    /// 占位符及补零宽度（仅 `{index}` 支持）
+    Field(Field, usize),
+}
+
+/// 重命名模板
+///
+/// 支持的占位符：
+///
+/// - 国家：`{flag}`、`{alpha2}`、`{alpha3}`、`{numeric}`、`{name_en}`、`{name_zh_cn}`、`{name_zh_tw}`
+/// - 行政区：`{subdivision}`（ISO 3166-2代码）、`{subdivision_en}`、`{subdivision_zh_cn}`、`{subdivision_zh_tw}`
+/// - 城市：`{city}`（英文名称）、`{city_zh_cn}`、`{city_zh_tw}`
+/// - 节点属性：`{multiplier}`（如 "0.5x"）、`{bandwidth}`（如 "100Mbps"、"1Gbps"）、`{line_type}`、`{carrier}`、
+///   `{streaming}`，从原名称中提取，多个值用 "/" 连接
+/// - 其他：`{index}`（同一国家内从1开始的序号，`{index:02}` 补零到两位）、`{original}`（原名称）
+///
+/// `{{` 和 `}}` 分别表示字面的花括号。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameTemplate {
+    parts: Vec<Part>,
+}
+
+impl RenameTemplate {
+    /// 解析模板，包含未知占位符或括号不匹配时返回 `ConfigError`
+    pub fn parse(template: &str) -> Result<Self, ParseError> {
+        let invalid = |reason: &str| ParseError::config_error(&format!("无效的重命名模板 {:?}: {}", template, reason));
+
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut placeholder = String::new();
+                    let mut closed = false;
+                    for c in chars.by_ref() {
+                        if c == '}' {
+                            closed = true;
+                            break;
+                        }
+                        placeholder.push(c);
+                    }
+                    if !closed {
+                        return Err(invalid("缺少 '}'"));
+                    }
+
+                    let (name, spec) = placeholder.split_once(':').unwrap_or((&placeholder, ""));
+                    let field = Field::from_name(name.trim()).ok_or_else(|| invalid(&format!("未知的占位符 {{{}}}", name)))?;
+
+                    let width = match spec {
+                        "" => 0,
+                        _ if field == Field::Index && spec.starts_with('0') => {
+                            spec.parse().map_err(|_| invalid(&format!("无效的格式 {:?}", spec)))?
+                        }
+                        _ => return Err(invalid(&format!("{{{}}} 不支持格式 {:?}", name, spec))),
+                    };
+
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(std::mem::take(&mut literal)));
+                    }
+                    parts.push(Part::Field(field, width));
+                }
+                '}' => return Err(invalid("多余的 '}'")),
+                _ => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+        Ok(Self { parts })
+    }
+
+    /// 代理组的默认名称模板 `"{flag} {name_en}"`
+    #[cfg(feature = "clash")]
+    pub(crate) fn flag_name_en() -> Self {
+        Self {
+            parts: vec![
+                Part::Field(Field::Flag, 0),
+                Part::Literal(" ".to_string()),
+                Part::Field(Field::NameEn, 0),
+            ],
+        }
+    }
+
+    /// 使用解析结果和序号生成名称
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use location_rs::{parse_detailed, RenameTemplate};
+    ///
+    /// let template = RenameTemplate::parse("{flag} {alpha2}-{city} {index:02}").unwrap();
+    /// let result = parse_detailed("洛杉矶 高速").unwrap();
+    /// assert_eq!(template.render(&result, 3, "洛杉矶 高速"), "🇺🇸 US-Los Angeles 03");
+    ///
+    /// // 没有城市时去掉多余的分隔符
+    /// let result = parse_detailed("美国 01").unwrap();
+    /// assert_eq!(template.render(&result, 1, "美国 01"), "🇺🇸 US 01");
+    /// ```
+    pub fn render(&self, m: &CountryMatch, index: usize, original: &str) -> String {
+        self.render_fields(&m.country, m.subdivision.as_ref(), m.city.as_ref(), index, original)
+    }
+
+    /// 只使用国家信息生成名称，行政区、城市、节点属性和原名称占位符为空
+    pub fn render_country(&self, country: &CountryInfo, index: usize) -> String {
+        self.render_fields(country, None, None, index, "")
+    }
+
+    fn render_fields(
+        &self,
+        country: &CountryInfo,
+        subdivision: Option<&SubdivisionInfo>,
+        city: Option<&CityInfo>,
+        index: usize,
+        original: &str,
+    ) -> String {
+        let attributes = extract_attributes(original);
+        let mut output = String::new();
+        // 最后一个非空占位符之后的字面文本的起始位置，去掉分隔符时不影响占位符的值
+        let mut literal_start = 0;
+        // 上一个占位符为空，需要去掉后面紧跟的分隔符
+        let mut after_empty = false;
+        // 去掉的分隔符中包含空白，在下一段非空内容前补一个空格
+        let mut pending_space = false;
+        for part in &self.parts {
+            let (field, width) = match part {
+                Part::Literal(text) => {
+                    let mut text = text.as_str();
+                    if after_empty {
+                        let trimmed = text.trim_start_matches(is_separator);
+                        pending_space |= text[..text.len() - trimmed.len()].contains(char::is_whitespace);
+                        text = trimmed;
+                    }
+                    if !text.is_empty() {
+                        push_space(&mut output, &mut pending_space);
+                        output.push_str(text);
+                    }
+                    after_empty = false;
+                    continue;
+                }
+                Part::Field(field, width) => (*field, *width),
+            };
+
+            let value = match field {
+                Field::Flag => country.flag(),
+                Field::Alpha2 => country.alpha2.clone(),
+                Field::Alpha3 => country.alpha3.clone(),
+                Field::Numeric => country.numeric.clone().unwrap_or_default(),
+                Field::NameEn => country.name_en.clone(),
+                Field::NameZhCn => country.name_zh_cn.clone(),
+                Field::NameZhTw => country.name_zh_tw.clone(),
+                Field::Subdivision => subdivision.map(|s| s.code.clone()).unwrap_or_default(),
+                Field::SubdivisionEn => subdivision.map(|s| s.name_en.clone()).unwrap_or_default(),
+                Field::SubdivisionZhCn => subdivision.map(|s| s.name_zh_cn.clone()).unwrap_or_default(),
+                Field::SubdivisionZhTw => subdivision.map(|s| s.name_zh_tw.clone()).unwrap_or_default(),
+                Field::City => city.map(|c| c.name_en.clone()).unwrap_or_default(),
+                Field::CityZhCn => city.map(|c| c.name_zh_cn.clone()).unwrap_or_default(),
+                Field::CityZhTw => city.map(|c| c.name_zh_tw.clone()).unwrap_or_default(),
+                Field::Multiplier => attributes.multiplier.as_ref().map(|m| format!("{}x", m.value)).unwrap_or_default(),
+                Field::Bandwidth => attributes.bandwidth.as_ref().map(|b| bandwidth(b.value)).unwrap_or_default(),
+                Field::LineType => join(&attributes.line_types),
+                Field::Carrier => join(&attributes.carriers),
+                Field::Streaming => join(&attributes.streaming),
+                Field::Index => format!("{:0width$}", index, width = width),
+                Field::Original => original.to_string(),
+            };
+            if value.is_empty() {
+                let kept = literal_start + output[literal_start..].trim_end_matches(is_separator).len();
+                pending_space |= output[kept..].contains(char::is_whitespace);
+                output.truncate(kept);
+                after_empty = true;
+            } else {
+                push_space(&mut output, &mut pending_space);
+                output.push_str(&value);
+                literal_start = output.len();
+                after_empty = false;
+            }
+        }
+
+        output
+    }
+}
+
+impl FromStr for RenameTemplate {
+    type Err = ParseError;
+
+    fn from_str(template: &str) -> Result<Self, Self::Err> {
+        Self::parse(template)
+    }
+}
+
+/// 带宽，不小于1000Mbps时以Gbps表示
+fn bandwidth(mbps: f64) -> String {
+    if mbps >= 1000.0 {
+        format!("{}Gbps", mbps / 1000.0)
+    } else {
+        format!("{}Mbps", mbps)
+    }
+}
+
+/// 用 "/" 连接多个属性值
+fn join<T: Display>(attributes: &[Attribute<T>]) -> String {
+    attributes.iter().map(|a| a.value.to_string()).collect::<Vec<_>>().join("/")
+}
+
+/// 在非空内容之前补上去掉分隔符时保留的空格
+fn push_space(output: &mut String, pending_space: &mut bool) {
+    if std::mem::take(pending_space) && !output.is_empty() && !output.ends_with(char::is_whitespace) {
+        output.push(' ');
+    }
+}
+
+/// 占位符为空时需要去掉的相邻分隔符，去掉的部分包含空白时保留一个空格
+const SEPARATORS: &[char] = &['-', '_', '|', '·', '/'];
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || SEPARATORS.contains(&c)
+}
+
+/// 按模板重命名节点，保证输出名称不重复
+///
+/// 无法解析国家的节点和信息节点保留原名称。重名时依次追加 " 2"、" 3" 等后缀。
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::rename::rename;
+///
+/// let names = rename(&["香港 A", "美国 B", "HK C", "官方网站"], "{alpha2} {index:02}").unwrap();
+/// assert_eq!(names, ["HK 01", "US 01", "HK 02", "官方网站"]);
+/// ```
+pub fn rename<S: AsRef<str>>(titles: &[S], template: &str) -> Result<Vec<String>, ParseError> {
+    let template = RenameTemplate::parse(template)?;
+    Ok(Parser::new().rename(titles, &template))
+}
+
+/// 使用指定解析器重命名节点
+pub(crate) fn rename_with<S: AsRef<str>>(titles: &[S], template: &RenameTemplate, parser: &Parser) -> Vec<String> {
+    let mut counters: HashMap<String, usize> = HashMap::new();
+    let names: Vec<String> = titles
+        .iter()
+        .map(|title| {
+            let title = title.as_ref();
+            if parser.classify(title).kind.is_pseudo() {
+                return title.trim().to_string();
+            }
+            match parser.parse_detailed(title) {
+                Ok(m) => {
+                    let index = counters.entry(m.country.alpha2.clone()).or_default();
+                    *index += 1;
+                    template.render(&m, *index, title)
+                }
+                Err(_) => title.trim().to_string(),
+            }
+        })
+        .collect();
+
+    deduplicate(names)
+}
+
+/// 为重复的名称追加序号后缀
+pub(crate) fn deduplicate(names: Vec<String>) -> Vec<String> {
+    let mut used: HashSet<String> = HashSet::new();
+    let counts = names.iter().fold(HashMap::new(), |mut counts: HashMap<&str, usize>, name| {
+        *counts.entry(name).or_default() += 1;
+        counts
+    });
+    // 先占用不重复的名称，避免后缀与它们冲突
+    used.extend(names.iter().filter(|name| counts[name.as_str()] == 1).cloned());
+
+    let mut output = Vec::with_capacity(names.len());
+    for name in &names {
+        if counts[name.as_str()] == 1 {
+            output.push(name.clone());
+            continue;
+        }
+
+        let mut candidate = name.clone();
+        let mut suffix = 1;
+        while !used.insert(candidate.clone()) {
+            suffix += 1;
+            candidate = format!("{} {}", name, suffix);
+        }
+        output.push(candidate);
+    }
+    output
+}
@@ -0,0 +1,189 @@
+//! 数据集版本与迁移
+//!
+//! 国家配置文件的 `version` 字段采用 `主版本.次版本` 格式。次版本升级只新增字段，
+//! 旧文件会在加载时逐级迁移到当前版本；主版本不同或高于当前版本的文件会被拒绝。
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::error::ParseError;
+
+type JsonObject = serde_json::Map<String, Value>;
+
+/// 数据集版本
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SchemaVersion {
+    /// 主版本
+    pub major: u32,
+    /// 次版本
+    pub minor: u32,
+}
+
+impl SchemaVersion {
+    /// 创建版本
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    /// 从 `主版本.次版本` 格式的字符串解析版本
+    pub fn parse(version: &str) -> Result<Self, ParseError> {
+        let invalid = || ParseError::config_error(&format!("无效的数据集版本: {:?}", version));
+
+        let (major, minor) = version.trim().split_once('.').ok_or_else(invalid)?;
+        let major = major.parse().map_err(|_| invalid())?;
+        let minor = minor.parse().map_err(|_| invalid())?;
+
+        Ok(Self::new(major, minor))
+    }
+}
+
+impl fmt::Display for SchemaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// 当前库使用的数据集版本
+pub const CURRENT_SCHEMA_VERSION: SchemaVersion = SchemaVersion::new(1, 6);
+
+/// 可以迁移的最旧数据集版本
+pub const MIN_SCHEMA_VERSION: SchemaVersion = SchemaVersion::new(1, 1);
+
+/// 单步迁移
+struct Migration {
+    /// 迁移前的版本
+    from: SchemaVersion,
+    /// 迁移后的版本
+    to: SchemaVersion,
+    /// 迁移函数，修改 `countries` 数组中的每个国家
+    apply: fn(&mut JsonObject, Option<&JsonObject>),
+}
+
+/// 按版本顺序排列的迁移步骤
+const MIGRATIONS: &[Migration] = &[
+    // 1.2: 新增ISO 3166-1数字代码
+    Migration {
+        from: SchemaVersion::new(1, 1),
+        to: SchemaVersion::new(1, 2),
+        apply: |country, embedded| fill_field(country, embedded, "numeric"),
+    },
+    // 1.3: 新增M49地区代码
+    Migration {
+        from: SchemaVersion::new(1, 2),
+        to: SchemaVersion::new(1, 3),
+        apply: |country, embedded| {
+            for field in ["region", "sub_region", "intermediate_region"] {
+                fill_field(country, embedded, field);
+            }
+        },
+    },
+    // 1.4: 新增国际电话区号
+    Migration {
+        from: SchemaVersion::new(1, 3),
+        to: SchemaVersion::new(1, 4),
+        apply: |country, embedded| {
+            fill_field_or(country, embedded, "calling_codes", Value::Array(Vec::new()))
+        },
+    },
+    // 1.5: 新增其他语言的名称
+    Migration {
+        from: SchemaVersion::new(1, 4),
+        to: SchemaVersion::new(1, 5),
+        apply: |country, embedded| {
+            fill_field_or(country, embedded, "names", Value::Object(JsonObject::new()))
+        },
+    },
+    // 1.6: 新增属地的主权国家
+    Migration {
+        from: SchemaVersion::new(1, 5),
+        to: SchemaVersion::new(1, 6),
+        apply: |country, embedded| fill_field(country, embedded, "sovereign"),
+    },
+];
+
+/// 将国家配置迁移到当前版本
+///
+/// 新增的字段优先使用内置数据集中同一alpha-2代码的值填充。
+pub(crate) fn migrate(mut value: Value) -> Result<Value, ParseError> {
+    let version = value
+        .get("version")
+        .and_then(Value::as_str)
+        .ok_or_else(|| ParseError::config_error("国家配置缺少版本号"))?;
+    let mut version = SchemaVersion::parse(version)?;
+
+    if version > CURRENT_SCHEMA_VERSION || version.major != CURRENT_SCHEMA_VERSION.major {
+        return Err(ParseError::config_error(&format!(
+            "数据集版本 {} 不受支持，当前库支持的版本为 {}",
+            version, CURRENT_SCHEMA_VERSION
+        )));
+    }
+
+    if version < MIN_SCHEMA_VERSION {
+        return Err(ParseError::config_error(&format!(
+            "数据集版本 {} 过旧，最低支持的版本为 {}",
+            version, MIN_SCHEMA_VERSION
+        )));
+    }
+
+    if version == CURRENT_SCHEMA_VERSION {
+        return Ok(value);
+    }
+
+    let embedded = embedded_countries()?;
+    for migration in MIGRATIONS {
+        if migration.from != version {
+            continue;
+        }
+
+        if let Some(countries) = value.get_mut("countries").and_then(Value::as_array_mut) {
+            for country in countries.iter_mut().filter_map(Value::as_object_mut) {
+                let alpha2 = country.get("alpha2").and_then(Value::as_str).unwrap_or_default();
+                let source = embedded.get(alpha2.to_uppercase().as_str());
+                (migration.apply)(country, source.and_then(Value::as_object));
+            }
+        }
+        version = migration.to;
+    }
+
+    value["version"] = Value::String(version.to_string());
+    Ok(value)
+}
+
+/// 内置数据集中按alpha-2代码索引的国家
+fn embedded_countries() -> Result<HashMap<String, Value>, ParseError> {
+    let value: Value = serde_json::from_str(include_str!("../resources/countries.json"))
+        .map_err(|e| ParseError::config_error(&format!("国家配置解析失败: {}", e)))?;
+
+    let countries = value
+        .get("countries")
+        .and_then(Value::as_array)
+        .map(|countries| {
+            countries
+                .iter()
+                .filter_map(|country| {
+                    let alpha2 = country.get("alpha2")?.as_str()?;
+                    Some((alpha2.to_string(), country.clone()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(countries)
+}
+
+/// 字段缺失时使用内置数据填充，内置数据中也没有时置为 `null`
+fn fill_field(country: &mut JsonObject, embedded: Option<&JsonObject>, field: &str) {
+    fill_field_or(country, embedded, field, Value::Null);
+}
+
+/// 字段缺失时使用内置数据填充，内置数据中也没有时使用默认值
+fn fill_field_or(country: &mut JsonObject, embedded: Option<&JsonObject>, field: &str, default: Value) {
+    if country.contains_key(field) {
+        return;
+    }
+
+    let value = embedded.and_then(|source| source.get(field)).cloned().unwrap_or(default);
+    country.insert(field.to_string(), value);
+}
@@ -0,0 +1,206 @@
+//! 订阅内容解析
+//!
+//! 自动识别订阅内容的格式（Base64编码的分享链接、分享链接列表、Clash YAML、sing-box JSON），
+//! 提取节点并按名称解析国家。需要启用 `subscription` 特性。
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::config::CountryInfo;
+use crate::error::ParseError;
+use crate::{clash, links, Parser};
+
+/// 订阅内容的格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SubscriptionFormat {
+    /// Base64编码的分享链接列表（支持URL安全字母表和无填充）
+    Base64,
+    /// 每行一个分享链接
+    Links,
+    /// Clash / Mihomo YAML配置
+    Clash,
+    /// sing-box JSON配置
+    SingBox,
+}
+
+impl fmt::Display for SubscriptionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Base64 => "base64",
+            Self::Links => "links",
+            Self::Clash => "clash",
+            Self::SingBox => "sing-box",
+        };
+        f.write_str(name)
+    }
+}
+
+/// 订阅中的节点
+#[derive(Debug, Clone)]
+pub struct Node {
+    /// 节点名称
+    pub name: String,
+    /// 协议，使用Clash配置中的 `type` 名称（如 "ss"、"vmess"）
+    pub protocol: String,
+    /// 服务器地址
+    pub server: String,
+    /// 服务器端口，配置中没有端口时为 `None`
+    pub port: Option<u16>,
+    /// 按节点名称解析的国家，名称中没有国家时使用服务器主机名
+    pub country: Result<CountryInfo, ParseError>,
+}
+
+/// 解析后的订阅
+#[derive(Debug, Clone)]
+pub struct Subscription {
+    /// 识别出的格式
+    pub format: SubscriptionFormat,
+    /// 节点列表，保持订阅中的顺序
+    pub nodes: Vec<Node>,
+    /// 无法解析的条目（如不支持的分享链接、缺少名称的Clash节点）
+    pub errors: Vec<ParseError>,
+}
+
+/// 不是代理节点的sing-box出站类型
+const SING_BOX_NON_PROXY_TYPES: &[&str] = &["direct", "block", "dns", "selector", "urltest"];
+
+/// 解析订阅内容
+///
+/// # 示例
+///
+/// ```rust
+/// use location_rs::subscription::{decode, SubscriptionFormat};
+///
+/// // "trojan://pw@hk.example.com:443#HK%2001" 的Base64编码
+/// let subscription = decode(b"dHJvamFuOi8vcHdAaGsuZXhhbXBsZS5jb206NDQzI0hLJTIwMDE=").unwrap();
+/// assert_eq!(subscription.format, SubscriptionFormat::Base64);
+/// assert_eq!(subscription.nodes[0].name, "HK 01");
+/// assert_eq!(subscription.nodes[0].country.as_ref().unwrap().alpha2, "HK");
+/// ```
+pub fn decode(bytes: &[u8]) -> Result<Subscription, ParseError> {
+    Parser::new().decode_subscription(bytes)
+}
+
+/// 使用指定解析器解析订阅内容
+pub(crate) fn decode_with(bytes: &[u8], parser: &Parser) -> Result<Subscription, ParseError> {
+    let text = std::str::from_utf8(bytes)
+        .map_err(|_| ParseError::config_error("订阅内容不是有效的UTF-8文本"))?;
+    let text = text.trim_start_matches('\u{feff}').trim();
+    if text.is_empty() {
+        return Err(ParseError::config_error("订阅内容为空"));
+    }
+
+    if text.starts_with('{') {
+        if let Ok(json) = serde_json::from_str::<Value>(text) {
+            if let Some(outbounds) = json.get("outbounds").and_then(Value::as_array) {
+                return Ok(decode_sing_box(outbounds, parser));
+            }
+        }
+    }
+
+    if is_clash(text) {
+        let mut nodes = Vec::new();
+        let mut errors = Vec::new();
+        for proxy in parser.parse_clash(text)? {
+            match proxy {
+                Ok(proxy) => nodes.push(clash_node(proxy, parser)),
+                Err(e) => errors.push(e),
+            }
+        }
+        return Ok(Subscription {
+            format: SubscriptionFormat::Clash,
+            nodes,
+            errors,
+        });
+    }
+
+    if text.contains("://") {
+        return Ok(decode_links(text, SubscriptionFormat::Links, parser));
+    }
+
+    let decoded = links::decode_base64(text)
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .filter(|decoded| decoded.contains("://"))
+        .ok_or_else(|| ParseError::config_error("无法识别的订阅格式"))?;
+    Ok(decode_links(&decoded, SubscriptionFormat::Base64, parser))
+}
+
+/// 是否为包含 `proxies` 列表的Clash配置
+fn is_clash(text: &str) -> bool {
+    serde_yaml::from_str::<clash::Value>(text)
+        .is_ok_and(|document| document.get("proxies").is_some_and(clash::Value::is_sequence))
+}
+
+fn decode_links(text: &str, format: SubscriptionFormat, parser: &Parser) -> Subscription {
+    let mut nodes = Vec::new();
+    let mut errors = Vec::new();
+
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        match parser.parse_link(line) {
+            Ok(link) => nodes.push(Node {
+                name: link.name,
+                protocol: link.protocol.as_str().to_string(),
+                server: link.server,
+                port: Some(link.port),
+                country: link.country,
+            }),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    Subscription { format, nodes, errors }
+}
+
+fn clash_node(proxy: clash::ClashProxy, parser: &Parser) -> Node {
+    let field = |key: &str| proxy.config.get(key);
+    let server = field("server").and_then(clash::Value::as_str).unwrap_or_default().to_string();
+    let country = proxy.country.or_else(|e| parser.parse_host(&server).map_err(|_| e));
+
+    Node {
+        protocol: field("type").and_then(clash::Value::as_str).unwrap_or_default().to_string(),
+        port: field("port").and_then(clash::Value::as_u64).and_then(|port| port.try_into().ok()),
+        name: proxy.name,
+        server,
+        country,
+    }
+}
+
+fn decode_sing_box(outbounds: &[Value], parser: &Parser) -> Subscription {
+    let nodes = outbounds
+        .iter()
+        .filter_map(|outbound| {
+            let protocol = outbound.get("type")?.as_str()?;
+            if SING_BOX_NON_PROXY_TYPES.contains(&protocol) {
+                return None;
+            }
+
+            let name = outbound.get("tag").and_then(Value::as_str).unwrap_or_default().trim().to_string();
+            let server = outbound.get("server").and_then(Value::as_str).unwrap_or_default().to_string();
+            let port = outbound.get("server_port").and_then(Value::as_u64).and_then(|port| port.try_into().ok());
+
+            Some(Node {
+                country: links::country_of(parser, &name, &server),
+                protocol: clash_type(protocol).to_string(),
+                name,
+                server,
+                port,
+            })
+        })
+        .collect();
+
+    Subscription {
+        format: SubscriptionFormat::SingBox,
+        nodes,
+        errors: Vec::new(),
+    }
+}
+
+/// 将sing-box的出站类型转换为Clash的类型名称
+fn clash_type(protocol: &str) -> &str {
+    match protocol {
+        "shadowsocks" => "ss",
+        "socks" => "socks5",
+        _ => protocol,
+    }
+}
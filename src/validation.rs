@@ -0,0 +1,274 @@
+//! 数据集校验
+//!
+//! 检查国家配置中的代码格式、重复别名以及可能导致误判的别名。
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::config::{Configuration, CountryInfo};
+use crate::parser::is_word_bounded;
+
+/// 校验问题类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationIssueKind {
+    /// 代码格式错误（alpha2 须为2个大写字母，alpha3 须为3个大写字母）
+    MalformedCode,
+    /// 两个国家使用了相同的代码
+    DuplicateCode,
+    /// 两个国家共用同一个别名
+    DuplicateAlias,
+    /// 别名与其他国家的名称相同
+    AliasConflictsWithName,
+    /// 别名与本国名称重复
+    AliasDuplicatesName,
+    /// 缺少名称
+    MissingName,
+    /// 缺少繁体中文名称
+    MissingTraditionalName,
+    /// 别名是其他国家名称的子串，可能导致误判
+    AliasShadowsName,
+    /// 引用了不存在的地区代码
+    UnknownRegion,
+    /// 主权国家不存在或本身也是属地
+    UnknownSovereign,
+}
+
+/// 单个校验问题
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// 问题类型
+    pub kind: ValidationIssueKind,
+    /// 相关国家的alpha-2代码
+    pub alpha2: String,
+    /// 相关词条（别名、名称或代码）
+    pub term: Option<String>,
+    /// 冲突的另一个国家的alpha-2代码
+    pub other: Option<String>,
+    /// 问题描述
+    pub message: String,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.alpha2, self.message)
+    }
+}
+
+/// 校验报告
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// 错误：数据不可用或会产生确定的歧义
+    pub errors: Vec<ValidationIssue>,
+    /// 警告：冗余数据或可能导致误判的数据
+    pub warnings: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// 是否没有错误
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// 获取指定类型的问题（包括错误和警告）
+    pub fn issues_of(&self, kind: ValidationIssueKind) -> impl Iterator<Item = &ValidationIssue> {
+        self.errors
+            .iter()
+            .chain(&self.warnings)
+            .filter(move |issue| issue.kind == kind)
+    }
+}
+
+impl ValidationIssue {
+    fn new(kind: ValidationIssueKind, country: &CountryInfo, message: String) -> Self {
+        Self {
+            kind,
+            alpha2: country.alpha2.clone(),
+            term: None,
+            other: None,
+            message,
+        }
+    }
+
+    fn term(mut self, term: &str) -> Self {
+        self.term = Some(term.to_string());
+        self
+    }
+
+    fn other(mut self, other: &CountryInfo) -> Self {
+        self.other = Some(other.alpha2.clone());
+        self
+    }
+}
+
+impl Configuration {
+    /// 校验国家数据集
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use location_rs::Configuration;
+    ///
+    /// let report = Configuration::load().unwrap().validate();
+    /// assert!(report.is_valid());
+    /// ```
+    pub fn validate(&self) -> ValidationReport {
+        let countries = self.get_countries();
+        let mut report = ValidationReport::default();
+
+        check_codes(countries, &mut report);
+        check_names(countries, &mut report);
+        check_aliases(countries, &mut report);
+        check_regions(self, &mut report);
+        check_sovereigns(countries, &mut report);
+
+        report
+    }
+}
+
+/// 检查代码格式和重复代码
+fn check_codes(countries: &[CountryInfo], report: &mut ValidationReport) {
+    use ValidationIssueKind::*;
+
+    let mut alpha2_owners: HashMap<&str, &CountryInfo> = HashMap::new();
+    let mut alpha3_owners: HashMap<&str, &CountryInfo> = HashMap::new();
+
+    for country in countries {
+        for (code, len, owners) in [
+            (&country.alpha2, 2, &mut alpha2_owners),
+            (&country.alpha3, 3, &mut alpha3_owners),
+        ] {
+            if !is_code(code, len) {
+                let message = format!("代码格式错误: {:?}", code);
+                report.errors.push(ValidationIssue::new(MalformedCode, country, message).term(code));
+            }
+
+            if let Some(owner) = owners.get(code.as_str()) {
+                let message = format!("代码 {} 与 {} 重复", code, owner.alpha2);
+                report
+                    .errors
+                    .push(ValidationIssue::new(DuplicateCode, country, message).term(code).other(owner));
+            } else {
+                owners.insert(code, country);
+            }
+        }
+    }
+}
+
+/// 检查名称是否缺失
+fn check_names(countries: &[CountryInfo], report: &mut ValidationReport) {
+    use ValidationIssueKind::*;
+
+    for country in countries {
+        if country.name_en.trim().is_empty() {
+            let message = "缺少英文名称".to_string();
+            report.errors.push(ValidationIssue::new(MissingName, country, message));
+        }
+        if country.name_zh_cn.trim().is_empty() {
+            let message = "缺少简体中文名称".to_string();
+            report.errors.push(ValidationIssue::new(MissingName, country, message));
+        }
+        if country.name_zh_tw.trim().is_empty() {
+            let message = "缺少繁体中文名称".to_string();
+            report.warnings.push(ValidationIssue::new(MissingTraditionalName, country, message));
+        }
+    }
+}
+
+/// 检查别名冲突
+fn check_aliases(countries: &[CountryInfo], report: &mut ValidationReport) {
+    use ValidationIssueKind::*;
+
+    let mut alias_owners: HashMap<String, &CountryInfo> = HashMap::new();
+
+    for country in countries {
+        for alias in &country.abbreviations {
+            let lower = alias.to_lowercase();
+            if lower.trim().is_empty() {
+                continue;
+            }
+
+            match alias_owners.get(&lower) {
+                Some(owner) if owner.alpha2 != country.alpha2 => {
+                    let message = format!("别名 {} 同时属于 {}", alias, owner.alpha2);
+                    let issue = ValidationIssue::new(DuplicateAlias, country, message);
+                    report.errors.push(issue.term(alias).other(owner));
+                }
+                Some(_) => {}
+                None => {
+                    alias_owners.insert(lower.clone(), country);
+                }
+            }
+
+            if names(country).any(|name| name == lower) {
+                let message = format!("别名 {} 与本国名称重复", alias);
+                let issue = ValidationIssue::new(AliasDuplicatesName, country, message);
+                report.warnings.push(issue.term(alias));
+            }
+
+            for other in countries.iter().filter(|other| other.alpha2 != country.alpha2) {
+                if names(other).any(|name| name == lower) {
+                    let message = format!("别名 {} 是 {} 的名称", alias, other.alpha2);
+                    let issue = ValidationIssue::new(AliasConflictsWithName, country, message);
+                    report.errors.push(issue.term(alias).other(other));
+                } else if names(other).any(|name| shadows(&name, &lower)) {
+                    let message = format!("别名 {} 包含在 {} 的名称中", alias, other.alpha2);
+                    let issue = ValidationIssue::new(AliasShadowsName, country, message);
+                    report.warnings.push(issue.term(alias).other(other));
+                }
+            }
+        }
+    }
+}
+
+/// 检查国家引用的地区代码是否存在
+fn check_regions(configuration: &Configuration, report: &mut ValidationReport) {
+    let known = |code: &str| configuration.get_regions().iter().any(|region| region.code == code);
+
+    for country in configuration.get_countries() {
+        for code in [&country.region, &country.sub_region, &country.intermediate_region]
+            .into_iter()
+            .flatten()
+        {
+            if !known(code) {
+                let message = format!("未知的地区代码: {}", code);
+                let issue = ValidationIssue::new(ValidationIssueKind::UnknownRegion, country, message);
+                report.errors.push(issue.term(code));
+            }
+        }
+    }
+}
+
+/// 检查属地引用的主权国家是否存在，且主权国家本身不是属地
+fn check_sovereigns(countries: &[CountryInfo], report: &mut ValidationReport) {
+    for country in countries {
+        let Some(code) = &country.sovereign else {
+            continue;
+        };
+
+        let sovereign = countries.iter().find(|other| &other.alpha2 == code);
+        if !sovereign.is_some_and(|sovereign| sovereign.sovereign.is_none() && sovereign.alpha2 != country.alpha2) {
+            let message = format!("无效的主权国家: {}", code);
+            let issue = ValidationIssue::new(ValidationIssueKind::UnknownSovereign, country, message);
+            report.errors.push(issue.term(code));
+        }
+    }
+}
+
+/// 国家的小写化名称
+fn names(country: &CountryInfo) -> impl Iterator<Item = String> + '_ {
+    [&country.name_en, &country.name_zh_cn, &country.name_zh_tw]
+        .into_iter()
+        .filter(|name| !name.is_empty())
+        .map(|name| name.to_lowercase())
+}
+
+/// 检查别名是否出现在名称中，纯ASCII的别名按完整单词匹配，与解析器一致
+fn shadows(name: &str, alias: &str) -> bool {
+    name.match_indices(alias)
+        .any(|(start, _)| !alias.is_ascii() || is_word_bounded(name, start, start + alias.len()))
+}
+
+/// 检查代码是否由指定数量的大写ASCII字母组成
+fn is_code(code: &str, len: usize) -> bool {
+    code.len() == len && code.chars().all(|c| c.is_ascii_uppercase())
+}
@@ -0,0 +1,100 @@
+use location_rs::attributes::{extract_attributes, LineType, StreamingTag};
+
+fn multiplier(text: &str) -> Option<f64> {
+    extract_attributes(text).multiplier.map(|m| m.value)
+}
+
+fn bandwidth(text: &str) -> Option<f64> {
+    extract_attributes(text).bandwidth.map(|b| b.value)
+}
+
+#[test]
+fn test_multiplier() {
+    assert_eq!(multiplier("香港 0.5x"), Some(0.5));
+    assert_eq!(multiplier("×2 日本"), Some(2.0));
+    assert_eq!(multiplier("X1.5 美国"), Some(1.5));
+    assert_eq!(multiplier("倍率1.5"), Some(1.5));
+    assert_eq!(multiplier("倍率：0.8 日本"), Some(0.8));
+    assert_eq!(multiplier("HK 2倍"), Some(2.0));
+    assert_eq!(multiplier("[0.1X] 香港"), Some(0.1));
+
+    assert_eq!(multiplier("HK01"), None);
+    assert_eq!(multiplier("Max2"), None);
+    assert_eq!(multiplier("2xlarge"), None);
+
+    let attributes = extract_attributes("倍率：0.8 日本");
+    assert_eq!(attributes.multiplier.unwrap().span, 0..12);
+}
+
+#[test]
+fn test_bandwidth() {
+    assert_eq!(bandwidth("1Gbps"), Some(1000.0));
+    assert_eq!(bandwidth("HK01 100M"), Some(100.0));
+    assert_eq!(bandwidth("500 Mbps"), Some(500.0));
+    assert_eq!(bandwidth("2.5G 日本"), Some(2500.0));
+    assert_eq!(bandwidth("10G 美国"), Some(10000.0));
+
+    // 流量单位不是带宽
+    assert_eq!(bandwidth("剩余流量：10GB"), None);
+    assert_eq!(bandwidth("2026-12-01"), None);
+
+    // 单个数字加 "G" 是移动网络代际，写明单位时才是带宽
+    assert_eq!(bandwidth("US 5G 01"), None);
+    assert_eq!(bandwidth("4G 香港"), None);
+    assert_eq!(bandwidth("5Gbps 日本"), Some(5000.0));
+    assert_eq!(bandwidth("1G带宽"), Some(1000.0));
+
+    let attributes = extract_attributes("美国 500 Mbps");
+    assert_eq!(attributes.bandwidth.unwrap().span, 7..15);
+}
+
+#[test]
+fn test_line_types() {
+    let attributes = extract_attributes("美国 CN2 GIA 家宽 原生IP");
+    let types: Vec<LineType> = attributes.line_types.iter().map(|a| a.value).collect();
+    assert_eq!(
+        types,
+        [LineType::Cn2Gia, LineType::Residential, LineType::NativeIp]
+    );
+    assert_eq!(attributes.line_types[0].span, 7..14);
+
+    let types: Vec<LineType> = extract_attributes("IPLC01 | IEPL | BGP | CN2GT")
+        .line_types
+        .iter()
+        .map(|a| a.value)
+        .collect();
+    assert_eq!(
+        types,
+        [
+            LineType::Iplc,
+            LineType::Iepl,
+            LineType::Bgp,
+            LineType::Cn2Gt
+        ]
+    );
+
+    // 需要完整单词
+    assert!(extract_attributes("XIPLCX").line_types.is_empty());
+}
+
+#[test]
+fn test_streaming() {
+    let attributes = extract_attributes("日本 Netflix解锁 油管 netflix");
+    let tags: Vec<StreamingTag> = attributes.streaming.iter().map(|a| a.value).collect();
+    assert_eq!(
+        tags,
+        [
+            StreamingTag::Netflix,
+            StreamingTag::Unlock,
+            StreamingTag::YouTube
+        ]
+    );
+    assert_eq!(attributes.streaming[0].span, 7..14);
+    assert_eq!(StreamingTag::DisneyPlus.to_string(), "Disney+");
+}
+
+#[test]
+fn test_empty() {
+    assert!(extract_attributes("香港 01").is_empty());
+    assert!(!extract_attributes("香港 IPLC").is_empty());
+}
@@ -0,0 +1,76 @@
+use location_rs::{MatchStage, ParseError, Parser, PatternKind};
+
+#[test]
+fn test_add_alias() {
+    let parser = Parser::builder()
+        .add_alias("SG", "狮城")
+        .add_alias("JP", "霓虹")
+        .add_alias("GB", "腐国")
+        .add_alias("RU", "毛子")
+        .build()
+        .unwrap();
+
+    assert_eq!(parser.parse("狮城 01").unwrap().alpha3, "SGP");
+    assert_eq!(parser.parse("【霓虹】专线").unwrap().alpha3, "JPN");
+    assert_eq!(parser.parse("腐国节点").unwrap().alpha3, "GBR");
+    assert_eq!(parser.parse("毛子-02").unwrap().alpha3, "RUS");
+    // 原有词条不受影响
+    assert_eq!(parser.parse("美国节点").unwrap().alpha3, "USA");
+
+    // 默认解析器不包含自定义别名
+    assert!(Parser::new().parse("狮城 01").is_err());
+}
+
+#[test]
+fn test_remove_alias() {
+    let parser = Parser::builder().remove_alias("US", "美利坚合众国").build().unwrap();
+    assert!(parser.parse("美利坚合众国 01").is_err());
+
+    // 先添加后移除，按顺序生效
+    let parser = Parser::builder()
+        .add_alias("SG", "狮城")
+        .remove_alias("SG", "狮城")
+        .build()
+        .unwrap();
+    assert!(parser.parse("狮城").is_err());
+}
+
+#[test]
+fn test_alias_errors() {
+    let result = Parser::builder().add_alias("XX", "未知").build();
+    assert!(matches!(result, Err(ParseError::ConfigError { .. })));
+
+    let result = Parser::builder().add_alias("SG", "  ").build();
+    assert!(matches!(result, Err(ParseError::ConfigError { .. })));
+}
+
+#[test]
+fn test_add_pattern() {
+    assert!(Parser::new().parse("~US").is_err());
+    assert_eq!(Parser::new().parse("|US 01").unwrap().alpha2, "US");
+
+    let parser = Parser::builder().add_pattern(PatternKind::Prefix, "~").build().unwrap();
+    assert_eq!(parser.parse("~US").unwrap().alpha3, "USA");
+
+    // 内置的后缀模式 "Vip" 是保留代码右侧的有效边界
+    assert_eq!(Parser::new().parse("UKVip").unwrap().alpha2, "GB");
+    assert!(Parser::new().parse("EUEdge").is_err());
+
+    let parser = Parser::builder().add_pattern(PatternKind::Suffix, "Edge").build().unwrap();
+    assert_eq!(parser.parse("EUEdge").unwrap().alpha2, "EU");
+}
+
+#[test]
+fn test_stage_toggles() {
+    let parser = Parser::builder().disable_stage(MatchStage::Alpha2).build().unwrap();
+    assert!(parser.parse("US Node").is_err());
+    assert_eq!(parser.parse("USA Node").unwrap().alpha3, "USA");
+    assert!(!parser.config().stages.contains(&MatchStage::Alpha2));
+
+    let parser = Parser::builder()
+        .disable_stage(MatchStage::Alpha2)
+        .enable_stage(MatchStage::Alpha2)
+        .build()
+        .unwrap();
+    assert_eq!(parser.parse("US Node").unwrap().alpha3, "USA");
+}
@@ -0,0 +1,54 @@
+use location_rs::{Configuration, MatchStage, Parser, ParserConfig};
+
+fn alpha2s(configuration: &Configuration, code: &str) -> Vec<String> {
+    configuration
+        .countries_by_calling_code(code)
+        .into_iter()
+        .map(|country| country.alpha2.clone())
+        .collect()
+}
+
+fn calling_code_parser() -> Parser {
+    let mut config = ParserConfig::default();
+    config.stages.insert(MatchStage::CallingCode);
+    Parser::with_config(config)
+}
+
+#[test]
+fn test_calling_code_lookup() {
+    let configuration = Configuration::load().unwrap();
+
+    assert_eq!(alpha2s(&configuration, "+81"), vec!["JP"]);
+    assert_eq!(alpha2s(&configuration, "852"), vec!["HK"]);
+    assert_eq!(alpha2s(&configuration, "+77"), vec!["KZ"]);
+    assert!(alpha2s(&configuration, "+999").is_empty());
+
+    // 共用区号返回多个候选
+    let nanp = alpha2s(&configuration, "+1");
+    assert!(nanp.len() > 2);
+    assert!(nanp.contains(&"US".to_string()) && nanp.contains(&"PR".to_string()));
+    assert_eq!(alpha2s(&configuration, "+7"), vec!["RU", "KZ"]);
+}
+
+#[test]
+fn test_calling_code_stage() {
+    let parser = calling_code_parser();
+
+    let result = parser.parse_detailed("+852 01").unwrap();
+    assert_eq!(result.country.alpha2, "HK");
+    assert_eq!(result.stage, MatchStage::CallingCode);
+
+    assert_eq!(parser.parse("节点+81").unwrap().alpha2, "JP");
+    assert_eq!(parser.parse("+44").unwrap().alpha2, "GB");
+    assert_eq!(parser.parse("+1-US").unwrap().alpha2, "US");
+    assert_eq!(parser.parse("+1-CA").unwrap().alpha2, "CA");
+
+    // +1 由美国和加拿大共用，无法确定国家
+    assert!(parser.parse("+1").is_err());
+}
+
+#[test]
+fn test_calling_code_stage_disabled_by_default() {
+    assert!(!MatchStage::CallingCode.is_default());
+    assert!(Parser::new().parse("+852").is_err());
+}
@@ -0,0 +1,58 @@
+use location_rs::attributes::{extract_attributes, Carrier};
+use location_rs::{parse_country_code, parse_detailed};
+
+#[test]
+fn test_carrier_does_not_produce_country() {
+    assert!(parse_country_code("CN2 GIA").is_err());
+    assert!(parse_country_code("中国移动 01").is_err());
+    assert!(parse_country_code("中国联通").is_err());
+    assert!(parse_country_code("CMHK 01").is_err());
+    assert!(parse_country_code("CNC").is_err());
+    assert!(parse_country_code("CMI 02").is_err());
+
+    // 单独出现的国家名称和代码不受影响
+    assert_eq!(parse_country_code("中国 01").unwrap().alpha2, "CN");
+    assert_eq!(parse_country_code("CN 01").unwrap().alpha2, "CN");
+}
+
+#[test]
+fn test_country_with_carrier() {
+    let result = parse_detailed("美国 CN2 GIA").unwrap();
+    assert_eq!(result.country.alpha2, "US");
+    assert_eq!(result.carrier, Some(Carrier::ChinaTelecom));
+
+    let result = parse_detailed("日本 中国联通").unwrap();
+    assert_eq!(result.country.alpha2, "JP");
+    assert_eq!(result.carrier, Some(Carrier::ChinaUnicom));
+
+    let result = parse_detailed("香港 CMHK 01").unwrap();
+    assert_eq!(result.country.alpha2, "HK");
+    assert_eq!(result.carrier, Some(Carrier::ChinaMobile));
+
+    let result = parse_detailed("美国 CNC").unwrap();
+    assert_eq!(result.country.alpha2, "US");
+    assert_eq!(result.carrier, Some(Carrier::ChinaUnicom));
+
+    let result = parse_detailed("移动 日本").unwrap();
+    assert_eq!(result.country.alpha2, "JP");
+    assert_eq!(result.carrier, Some(Carrier::ChinaMobile));
+
+    let result = parse_detailed("香港-移动-01").unwrap();
+    assert_eq!(result.country.alpha2, "HK");
+    assert_eq!(result.carrier, Some(Carrier::ChinaMobile));
+
+    assert_eq!(parse_detailed("HK 01").unwrap().carrier, None);
+}
+
+#[test]
+fn test_extract_carriers() {
+    let attributes = extract_attributes("美国 CN2 GIA 联通");
+    let carriers: Vec<Carrier> = attributes.carriers.iter().map(|c| c.value).collect();
+    assert_eq!(carriers, [Carrier::ChinaTelecom, Carrier::ChinaUnicom]);
+    assert!(!attributes.is_empty());
+
+    assert_eq!(
+        Carrier::ChinaMobile.to_string(),
+        Carrier::ChinaMobile.as_str()
+    );
+}
@@ -0,0 +1,45 @@
+use location_rs::{parse_country_code, parse_detailed, MatchStage, Parser, ParserConfig};
+
+fn country_of(text: &str) -> String {
+    parse_country_code(text).unwrap().alpha2
+}
+
+#[test]
+fn test_city_only() {
+    assert_eq!(country_of("洛杉矶 01"), "US");
+    assert_eq!(country_of("Tokyo-NTT"), "JP");
+    assert_eq!(country_of("法兰克福"), "DE");
+    assert_eq!(country_of("Ashburn"), "US");
+    assert_eq!(country_of("圣何塞"), "US");
+    assert_eq!(country_of("首尔"), "KR");
+    assert_eq!(country_of("San Jose 01"), "US");
+}
+
+#[test]
+fn test_city_reported() {
+    let result = parse_detailed("洛杉矶 01").unwrap();
+    assert_eq!(result.stage, MatchStage::City);
+    assert_eq!(result.city.unwrap().name_en, "Los Angeles");
+    assert_eq!(result.subdivision.unwrap().code, "US-CA");
+
+    let result = parse_detailed("美国洛杉矶").unwrap();
+    assert_eq!(result.country.alpha2, "US");
+    assert_eq!(result.city.unwrap().name_en, "Los Angeles");
+}
+
+#[test]
+fn test_country_name_wins_over_city() {
+    // 城市阶段的优先级低于明确的国家名称
+    let result = parse_detailed("德国 洛杉矶").unwrap();
+    assert_eq!(result.country.alpha2, "DE");
+    assert!(result.city.is_none());
+}
+
+#[test]
+fn test_city_stage_disabled() {
+    let mut config = ParserConfig::default();
+    config.stages.remove(&MatchStage::City);
+    let parser = Parser::with_config(config);
+
+    assert!(parser.parse("Ashburn").is_err());
+}
@@ -0,0 +1,167 @@
+#![cfg(feature = "clash")]
+
+use location_rs::clash::{parse_proxies, Value};
+use location_rs::{ClashProxy, ParseError, Parser, ParserConfig};
+
+const CONFIG: &str = r#"
+port: 7890
+mode: rule
+proxies:
+  - name: "@HK Vip1"
+    type: ss
+    server: hk.example.com
+    port: 8388
+    cipher: aes-128-gcm
+    password: secret
+    udp: true
+  - name: "【游戏加速】US-纽约-01节点"
+    type: vmess
+    server: us.example.com
+    port: 443
+    uuid: 00000000-0000-0000-0000-000000000000
+    ws-opts:
+      path: /ws
+      headers:
+        Host: cdn.example.com
+  - name: "官方网站"
+    type: trojan
+    server: example.com
+    port: 443
+proxy-groups:
+  - name: Proxy
+    type: select
+    proxies: ["@HK Vip1"]
+"#;
+
+fn valid_proxies(yaml: &str) -> Vec<ClashProxy> {
+    parse_proxies(yaml).unwrap().into_iter().map(Result::unwrap).collect()
+}
+
+#[test]
+fn test_parse_proxies() {
+    let proxies = valid_proxies(CONFIG);
+    assert_eq!(proxies.len(), 3);
+
+    assert_eq!(proxies[0].name, "@HK Vip1");
+    assert_eq!(proxies[0].country.as_ref().unwrap().alpha2, "HK");
+    assert_eq!(proxies[1].country.as_ref().unwrap().alpha2, "US");
+    assert!(proxies[2].country.is_err(), "{:?}", proxies[2].country);
+}
+
+#[test]
+fn test_proxy_fields_preserved() {
+    let proxies = valid_proxies(CONFIG);
+
+    let ss = &proxies[0].config;
+    assert_eq!(ss.len(), 7);
+    assert_eq!(ss["cipher"], "aes-128-gcm");
+    assert_eq!(ss["udp"], true);
+
+    let vmess = &proxies[1].config;
+    assert_eq!(vmess["ws-opts"]["headers"]["Host"], "cdn.example.com");
+    assert!(matches!(vmess["port"], Value::Number(_)));
+}
+
+#[test]
+fn test_parse_with_custom_config() {
+    let parser = Parser::with_config(ParserConfig {
+        resolve_sovereign: true,
+        ..Default::default()
+    });
+
+    let proxies = parser.parse_clash(CONFIG).unwrap();
+    assert_eq!(proxies[0].as_ref().unwrap().country.as_ref().unwrap().alpha2, "CN");
+}
+
+#[test]
+fn test_invalid_documents() {
+    assert!(parse_proxies("port: 7890").unwrap().is_empty());
+    assert!(parse_proxies("proxies:").unwrap().is_empty());
+
+    for yaml in ["proxies: [", "proxies: hk"] {
+        assert!(
+            matches!(parse_proxies(yaml), Err(ParseError::ConfigError { .. })),
+            "{}",
+            yaml
+        );
+    }
+}
+
+#[test]
+fn test_invalid_proxies() {
+    let yaml = r#"
+proxies:
+  - { name: "香港 01", type: ss, server: hk.example.com, port: 443 }
+  - { type: ss, server: nameless.example.com, port: 443 }
+  - HK
+  - { name: "日本 02", type: vmess, server: jp.example.com, port: 443 }
+"#;
+    let proxies = parse_proxies(yaml).unwrap();
+    assert_eq!(proxies.len(), 4);
+
+    assert_eq!(proxies[0].as_ref().unwrap().country.as_ref().unwrap().alpha2, "HK");
+    assert!(matches!(proxies[1], Err(ParseError::ConfigError { .. })));
+    assert!(matches!(proxies[2], Err(ParseError::ConfigError { .. })));
+    assert_eq!(proxies[3].as_ref().unwrap().country.as_ref().unwrap().alpha2, "JP");
+}
+
+#[test]
+fn test_proxy_groups() {
+    use location_rs::clash::{proxy_groups, ProxyGroupOptions, ProxyGroupType};
+    use location_rs::group::group_by_country;
+    use location_rs::RenameTemplate;
+
+    let groups = group_by_country(&["香港 01", "日本 01", "HK 02", "官方网站"]);
+
+    let options = ProxyGroupOptions::default();
+    assert_eq!(options.name_template, RenameTemplate::parse("{flag} {name_en}").unwrap());
+    let yaml = proxy_groups(&groups, &options).unwrap();
+    let document: Value = serde_yaml::from_str(&yaml).unwrap();
+    let entries = document["proxy-groups"].as_sequence().unwrap();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0]["name"], "🇭🇰 Hong Kong");
+    assert_eq!(entries[0]["type"], "url-test");
+    assert_eq!(entries[0]["interval"], 300);
+    assert_eq!(entries[0]["proxies"][1], "HK 02");
+    assert_eq!(entries[2]["name"], "Others");
+
+    let options = ProxyGroupOptions {
+        group_type: ProxyGroupType::Select,
+        name_template: RenameTemplate::parse("{index:02}-{name_zh_cn}").unwrap(),
+        others_name: "其他".to_string(),
+        ..Default::default()
+    };
+    let yaml = proxy_groups(&groups, &options).unwrap();
+    let document: Value = serde_yaml::from_str(&yaml).unwrap();
+    let entries = document["proxy-groups"].as_sequence().unwrap();
+    assert_eq!(entries[1]["name"], "02-日本");
+    assert_eq!(entries[1]["type"], "select");
+    assert!(entries[1].get("url").is_none());
+    assert_eq!(entries[2]["name"], "其他");
+}
+
+#[test]
+fn test_proxy_group_names_unique() {
+    use location_rs::clash::{proxy_groups, ProxyGroupOptions};
+    use location_rs::group::group_by_country;
+    use location_rs::RenameTemplate;
+
+    let options = ProxyGroupOptions {
+        name_template: RenameTemplate::parse("Auto").unwrap(),
+        others_name: "Auto".to_string(),
+        ..Default::default()
+    };
+    let yaml = proxy_groups(
+        &group_by_country(&["香港 01", "日本 01", "官方网站"]),
+        &options,
+    )
+    .unwrap();
+    let document: Value = serde_yaml::from_str(&yaml).unwrap();
+    let names: Vec<&str> = document["proxy-groups"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .map(|g| g["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["Auto", "Auto 2", "Auto 3"]);
+}
@@ -0,0 +1,155 @@
+use location_rs::classify::{classify, Date, Expiry, NodeKind};
+use location_rs::group::group_by_country;
+use location_rs::rename::rename;
+use location_rs::{ClassifierConfig, Parser, ParserConfig};
+
+#[test]
+fn test_kinds() {
+    for (text, kind) in [
+        ("剩余流量：120GB", NodeKind::Info),
+        ("套餐到期：2099-12-01", NodeKind::Info),
+        ("Traffic Reset: 3 days", NodeKind::Info),
+        ("官网 example.com", NodeKind::Ad),
+        ("Telegram交流群", NodeKind::Ad),
+        ("套餐已过期", NodeKind::Expired),
+        ("Expired", NodeKind::Expired),
+        ("香港 01", NodeKind::Normal),
+        ("日本 IPLC 解锁流媒体", NodeKind::Normal),
+    ] {
+        assert_eq!(classify(text).kind, kind, "{}", text);
+    }
+}
+
+#[test]
+fn test_real_nodes_are_normal() {
+    let names = [
+        "香港 大流量 01",
+        "美国 不限流量",
+        "香港 02 | 流量倍率 0.5",
+        "香港 01 (剩余 3 节点)",
+        "日本 Telegram 专用",
+        "新加坡 Website",
+        "HK 客服专用",
+        "日本 02 官网推荐",
+    ];
+    for name in names {
+        assert_eq!(classify(name).kind, NodeKind::Normal, "{}", name);
+    }
+
+    // 没有流量、日期或天数的订阅信息关键词不构成信息节点
+    assert_eq!(classify("剩余流量：未知").kind, NodeKind::Normal);
+
+    let groups = group_by_country(&names);
+    assert!(groups.iter().all(|group| group.country.is_some()));
+}
+
+#[test]
+fn test_traffic() {
+    let result = classify("剩余流量：120GB");
+    let traffic = result.traffic.unwrap();
+    assert_eq!(traffic.value, 120 << 30);
+    assert_eq!(traffic.span, 15..20);
+
+    assert_eq!(classify("已用流量 1.5 TB").traffic.unwrap().value, 3 << 39);
+    assert_eq!(
+        classify("剩余流量：512MiB").traffic.unwrap().value,
+        512 << 20
+    );
+    assert!(classify("剩余流量：未知").traffic.is_none());
+}
+
+#[test]
+fn test_expiry() {
+    let date = |year, month, day| Expiry::Date(Date { year, month, day });
+
+    let result = classify("套餐到期：2099-12-01");
+    assert_eq!(result.expiry.as_ref().unwrap().value, date(2099, 12, 1));
+    assert_eq!(result.expiry.unwrap().span, 15..25);
+
+    assert_eq!(
+        classify("到期时间：2099年1月5日").expiry.unwrap().value,
+        date(2099, 1, 5)
+    );
+    assert_eq!(
+        classify("过期时间 2099.12.01").expiry.unwrap().value,
+        date(2099, 12, 1)
+    );
+    assert_eq!(
+        classify("Traffic Reset: 3 days").expiry.unwrap().value,
+        Expiry::Days(3)
+    );
+    assert_eq!(
+        classify("距离下次重置剩余：12 天").expiry.unwrap().value,
+        Expiry::Days(12)
+    );
+
+    // 不存在的日期不是到期时间
+    for text in ["到期：2099-02-31", "到期：2099-04-31", "到期：2099-02-29", "到期：2099-13-01"] {
+        assert_eq!(classify(text).expiry, None, "{}", text);
+    }
+    assert_eq!(classify("到期：2096-02-29").expiry.unwrap().value, date(2096, 2, 29));
+    assert_eq!(classify("到期：2000-02-29").expiry.unwrap().value, date(2000, 2, 29));
+
+    // 到期日期早于今天时视为过期
+    let result = classify("套餐到期：2020/1/5");
+    assert_eq!(result.kind, NodeKind::Expired);
+    assert_eq!(result.expiry.unwrap().value, date(2020, 1, 5));
+    assert!(
+        Date::today()
+            > Date {
+                year: 2020,
+                month: 1,
+                day: 5
+            }
+    );
+}
+
+#[test]
+fn test_reference_date() {
+    let parser = |year, month, day| {
+        Parser::with_config(ParserConfig {
+            classifier: ClassifierConfig {
+                today: Some(Date { year, month, day }),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    };
+
+    assert_eq!(parser(2026, 12, 1).classify("套餐到期：2026-12-01").kind, NodeKind::Info);
+    assert_eq!(parser(2026, 12, 2).classify("套餐到期：2026-12-01").kind, NodeKind::Expired);
+}
+
+#[test]
+fn test_custom_keywords() {
+    let mut classifier = ClassifierConfig::default();
+    classifier.ad_keywords.push("购买".to_string());
+    classifier.info_keywords.retain(|keyword| keyword != "套餐");
+    let parser = Parser::with_config(ParserConfig {
+        classifier,
+        ..Default::default()
+    });
+
+    assert_eq!(parser.classify("购买请访问").kind, NodeKind::Ad);
+    assert_eq!(parser.classify("套餐A").kind, NodeKind::Normal);
+}
+
+#[test]
+fn test_pseudo_nodes_have_no_country() {
+    let names = [
+        "香港 01",
+        "剩余流量：10GB",
+        "官网 hk.example.com",
+        "美国 01",
+    ];
+
+    let groups = group_by_country(&names);
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[2].names, ["剩余流量：10GB", "官网 hk.example.com"]);
+
+    let renamed = rename(&names, "{alpha2} {index:02}").unwrap();
+    assert_eq!(
+        renamed,
+        ["HK 01", "剩余流量：10GB", "官网 hk.example.com", "US 01"]
+    );
+}
@@ -0,0 +1,135 @@
+use location_rs::filter::filter_regex;
+use location_rs::{parse_country_code, parse_detailed, MatchStage, Parser, ParserConfig};
+use regex::Regex;
+
+fn country_regex(code: &str) -> Regex {
+    let country = parse_country_code(code).unwrap();
+    Regex::new(&filter_regex(&country).unwrap()).unwrap()
+}
+
+#[test]
+fn test_flag_stage() {
+    let result = parse_detailed("🇭🇰 香港 01").unwrap();
+    assert_eq!(result.country.alpha2, "HK");
+    assert_eq!(result.stage, MatchStage::Flag);
+    assert_eq!(result.span, 0..8);
+
+    // 区域指示符从头开始两两配对，"🇺🇸🇬🇧" 中间的 "🇸🇬" 不是新加坡
+    assert_eq!(parse_country_code("🇺🇸🇬🇧 Relay").unwrap().alpha2, "US");
+    assert_eq!(parse_country_code("Node 🇯🇵").unwrap().alpha2, "JP");
+}
+
+#[test]
+fn test_flag_priority() {
+    // 国旗优先于名称，与名称中的国家不一致时以国旗为准
+    let result = parse_detailed("美国 🇭🇰").unwrap();
+    assert_eq!(result.country.alpha2, "HK");
+    assert_eq!(result.stage, MatchStage::Flag);
+
+    // 禁用国旗阶段后恢复按名称解析
+    let mut config = ParserConfig::default();
+    config.stages.remove(&MatchStage::Flag);
+    let parser = Parser::with_config(config);
+    assert_eq!(parser.parse("美国 🇭🇰").unwrap().alpha2, "US");
+}
+
+#[test]
+fn test_regex_contains_terms() {
+    let hk = parse_country_code("HK").unwrap();
+    let regex = filter_regex(&hk).unwrap();
+
+    assert!(regex.starts_with("(?i)🇭🇰|香港|"));
+    assert!(regex.contains("Hong Kong"));
+    assert!(regex.contains("(?:HKG|HK)"));
+    assert!(regex.contains("Гонконг"));
+}
+
+#[test]
+fn test_regex_agrees_with_parser() {
+    let titles = [
+        "🇭🇰 01",
+        "香港 IPLC",
+        "HK01",
+        "@HK Vip1",
+        "【HKG】高速",
+        "Hong Kong 02",
+        "홍콩 프리미엄",
+        "日本 01",
+        "JP-02",
+        "美国 洛杉矶",
+        "USA 01",
+        "🇺🇸 Seattle",
+        "官方网站",
+        "剩余流量",
+    ];
+
+    for code in ["HK", "JP", "US"] {
+        let regex = country_regex(code);
+        for title in titles {
+            let parsed = parse_country_code(title).is_ok_and(|c| c.alpha2 == code);
+            assert_eq!(regex.is_match(title), parsed, "{} {:?}", code, title);
+        }
+    }
+}
+
+#[test]
+fn test_code_boundaries() {
+    let regex = country_regex("US");
+
+    assert!(regex.is_match("US"));
+    assert!(regex.is_match("01US"));
+    assert!(regex.is_match("[US] 01"));
+    assert!(!regex.is_match("Status"));
+    assert!(!regex.is_match("xUS"));
+}
+
+#[test]
+fn test_reserved_codes() {
+    let regex = country_regex("GB");
+
+    assert!(regex.is_match("UK 01"));
+    assert!(regex.is_match("🇬🇧"));
+    assert!(!regex.is_match("UKR"));
+    assert!(regex.is_match("uk node"));
+    assert!(regex.is_match("UKVip"));
+    assert!(!regex.is_match("UKvip"));
+    assert!(!regex.is_match("Ukraine"));
+
+    // 保留代码区分大小写
+    let regex = country_regex("RU");
+    assert!(regex.is_match("SU-02"));
+    assert!(!regex.is_match("Su 01"));
+    assert_eq!(parse_country_code("UK 01").unwrap().alpha2, "GB");
+}
+
+#[test]
+fn test_escape() {
+    let parser = Parser::builder().add_alias("US", "U.S.").build().unwrap();
+    let us = parser.parse("美国").unwrap();
+    let regex = Regex::new(&parser.filter_regex(&us).unwrap()).unwrap();
+
+    assert!(regex.is_match("U.S. 01"));
+    assert!(!regex.is_match("UxSx"));
+}
+
+#[test]
+fn test_follows_parser_config() {
+    let mut config = ParserConfig::default();
+    config.stages.remove(&MatchStage::Alpha2);
+    config.stages.remove(&MatchStage::Flag);
+    let parser = Parser::with_config(config);
+
+    let jp = parser.parse("日本").unwrap();
+    let regex = parser.filter_regex(&jp).unwrap();
+    assert!(!regex.contains("🇯🇵"));
+    assert!(regex.contains("(?:JPN)"));
+
+    let rollup = Parser::with_config(ParserConfig {
+        resolve_sovereign: true,
+        ..Default::default()
+    });
+    let cn = rollup.parse("中国").unwrap();
+    let regex = Regex::new(&rollup.filter_regex(&cn).unwrap()).unwrap();
+    assert!(regex.is_match("香港 01"));
+    assert!(regex.is_match("中国 01"));
+}
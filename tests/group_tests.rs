@@ -0,0 +1,49 @@
+use location_rs::group::group_by_country;
+use location_rs::{Parser, ParserConfig};
+
+#[test]
+fn test_group_order() {
+    let names = [
+        "🇯🇵 东京 01",
+        "香港 01",
+        "美国 01",
+        "HK 02",
+        "官方网站",
+        "日本 大阪",
+        "到期时间",
+    ];
+    let groups = group_by_country(&names);
+
+    let countries: Vec<&str> = groups
+        .iter()
+        .filter_map(|g| g.country.as_ref())
+        .map(|c| c.alpha2.as_str())
+        .collect();
+    assert_eq!(countries, ["JP", "HK", "US"]);
+    assert_eq!(groups[0].names, ["🇯🇵 东京 01", "日本 大阪"]);
+    assert_eq!(groups[1].names, ["香港 01", "HK 02"]);
+
+    let others = groups.last().unwrap();
+    assert!(others.is_others());
+    assert_eq!(others.names, ["官方网站", "到期时间"]);
+}
+
+#[test]
+fn test_group_without_others() {
+    let groups = group_by_country(&["HK 01"]);
+    assert_eq!(groups.len(), 1);
+    assert!(!groups[0].is_others());
+    assert!(group_by_country::<&str>(&[]).is_empty());
+}
+
+#[test]
+fn test_group_with_parser_config() {
+    let parser = Parser::with_config(ParserConfig {
+        resolve_sovereign: true,
+        ..Default::default()
+    });
+
+    let groups = parser.group_by_country(&["香港 01", "中国 02", "澳门 03"]);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].country.as_ref().unwrap().alpha2, "CN");
+}
@@ -0,0 +1,55 @@
+use location_rs::{parse_host, ParseError, Parser, ParserConfig};
+
+fn country_of(host: &str) -> String {
+    parse_host(host).unwrap().alpha2
+}
+
+#[test]
+fn test_cctld() {
+    assert_eq!(country_of("node.hk"), "HK");
+    assert_eq!(country_of("www.example.co.jp"), "JP");
+    assert_eq!(country_of("example.co.uk"), "GB");
+    assert_eq!(country_of("https://user@node.example.de:8443/path?x=1"), "DE");
+}
+
+#[test]
+fn test_host_labels() {
+    assert_eq!(country_of("jp1.example.co.jp"), "JP");
+    assert_eq!(country_of("sg.provider.io"), "SG");
+    assert_eq!(country_of("hk01.example.com"), "HK");
+    assert_eq!(country_of("jp-tyo.example.net"), "JP");
+    // 主机标签优先于顶级域名
+    assert_eq!(country_of("us2.example.de"), "US");
+    assert_eq!(country_of("usa1.example.com"), "US");
+}
+
+#[test]
+fn test_common_word_labels() {
+    for host in ["my.provider.com", "id.example.com", "in.example.com", "it.example.com", "no.example.com"] {
+        assert!(matches!(parse_host(host), Err(ParseError::NotFound { .. })), "{}", host);
+    }
+
+    // 带有编号时仍然是国家
+    assert_eq!(country_of("my01.provider.com"), "MY");
+    assert_eq!(country_of("id2.example.com"), "ID");
+    // 顶级域名不受影响
+    assert_eq!(country_of("my.example.it"), "IT");
+}
+
+#[test]
+fn test_non_geographic_tlds() {
+    for host in ["api.example.io", "example.co", "live.example.tv", "node.example.com"] {
+        assert!(matches!(parse_host(host), Err(ParseError::NotFound { .. })), "{}", host);
+    }
+
+    let mut config = ParserConfig::default();
+    config.non_geographic_tlds.remove("io");
+    let parser = Parser::with_config(config);
+    assert_eq!(parser.parse_host("api.example.io").unwrap().alpha2, "IO");
+}
+
+#[test]
+fn test_invalid_host() {
+    assert!(matches!(parse_host(""), Err(ParseError::InvalidInput { .. })));
+    assert!(matches!(parse_host("https://[::1]:443/"), Err(ParseError::InvalidInput { .. })));
+}
@@ -0,0 +1,62 @@
+use location_rs::{parse_country_code, IataConflict, MatchStage, Parser, ParserConfig};
+
+fn iata_parser(iata_conflict: IataConflict) -> Parser {
+    let mut config = ParserConfig::default();
+    config.stages.insert(MatchStage::Iata);
+    config.iata_conflict = iata_conflict;
+    Parser::with_config(config)
+}
+
+#[test]
+fn test_iata_disabled_by_default() {
+    assert!(!ParserConfig::default().stages.contains(&MatchStage::Iata));
+    assert_eq!(parse_country_code("FRA 01").unwrap().alpha2, "FR");
+    assert!(parse_country_code("NRT-01").map_or(true, |c| c.alpha2 != "JP"));
+}
+
+#[test]
+fn test_iata_codes() {
+    let parser = iata_parser(IataConflict::default());
+
+    for (text, expected) in [
+        ("LAX 01", "US"),
+        ("NRT-01", "JP"),
+        ("SJC", "US"),
+        ("AMS", "NL"),
+        ("SIN 02", "SG"),
+    ] {
+        let result = parser.parse_detailed(text).unwrap();
+        assert_eq!(result.country.alpha2, expected, "{}", text);
+        assert_eq!(result.stage, MatchStage::Iata, "{}", text);
+    }
+
+    let result = parser.parse_detailed("NRT-01").unwrap();
+    assert_eq!(result.airport.unwrap().code, "NRT");
+    assert_eq!(result.city.unwrap().name_en, "Tokyo");
+    assert_eq!(result.subdivision.unwrap().code, "JP-13");
+}
+
+#[test]
+fn test_iata_requires_uppercase() {
+    let parser = iata_parser(IataConflict::default());
+    assert!(parser
+        .parse_detailed("lax")
+        .map_or(true, |m| m.airport.is_none()));
+}
+
+#[test]
+fn test_alpha3_conflict_policy() {
+    let result = iata_parser(IataConflict::PreferAlpha3)
+        .parse_detailed("FRA 01")
+        .unwrap();
+    assert_eq!(result.country.alpha2, "FR");
+    assert_eq!(result.stage, MatchStage::Alpha3);
+    assert!(result.airport.is_none());
+
+    let result = iata_parser(IataConflict::PreferIata)
+        .parse_detailed("FRA 01")
+        .unwrap();
+    assert_eq!(result.country.alpha2, "DE");
+    assert_eq!(result.stage, MatchStage::Iata);
+    assert_eq!(result.city.unwrap().name_en, "Frankfurt");
+}
@@ -242,7 +242,9 @@ fn test_edge_cases() {
     assert_eq!(parse_country_code("韩国2号通道").unwrap().alpha3, "KOR");
     assert_eq!(parse_country_code("美国节点3").unwrap().alpha3, "USA");
     assert_eq!(parse_country_code("US1").unwrap().alpha3, "USA");
-    assert_eq!(parse_country_code("CN2").unwrap().alpha3, "CHN");
+    assert_eq!(parse_country_code("CN1").unwrap().alpha3, "CHN");
+    // "CN2" 是电信网络名称，不是国家
+    assert!(parse_country_code("CN2").is_err());
     assert_eq!(parse_country_code("JP3").unwrap().alpha3, "JPN");
     assert_eq!(parse_country_code("KR4").unwrap().alpha3, "KOR");
     assert_eq!(parse_country_code("SG5").unwrap().alpha3, "SGP");
@@ -0,0 +1,91 @@
+#![cfg(feature = "links")]
+
+use location_rs::links::{parse_link, Protocol};
+use location_rs::ParseError;
+
+#[test]
+fn test_fragment_names() {
+    let link = parse_link(
+        "vless://uuid@us.example.com:8443?security=reality&type=grpc#US-%E7%BA%BD%E7%BA%A6-01",
+    )
+    .unwrap();
+    assert_eq!(link.protocol, Protocol::Vless);
+    assert_eq!(link.name, "US-纽约-01");
+    assert_eq!((link.server.as_str(), link.port), ("us.example.com", 8443));
+    assert_eq!(link.country.unwrap().alpha2, "US");
+
+    let link = parse_link("tuic://uuid:pass@[2001:db8::1]:443?congestion_control=bbr#%F0%9F%87%AF%F0%9F%87%B5%20Tokyo").unwrap();
+    assert_eq!(link.protocol, Protocol::Tuic);
+    assert_eq!(link.server, "2001:db8::1");
+    assert_eq!(link.country.unwrap().alpha2, "JP");
+}
+
+#[test]
+fn test_hysteria2_default_port() {
+    let link = parse_link("hy2://auth@hk.example.com/?sni=example.com#%E9%A6%99%E6%B8%AF").unwrap();
+    assert_eq!(link.protocol, Protocol::Hysteria2);
+    assert_eq!(link.port, 443);
+    assert_eq!(link.country.unwrap().alpha2, "HK");
+}
+
+#[test]
+fn test_shadowsocks() {
+    // SIP002，userinfo为无填充的URL安全Base64
+    let link = parse_link(
+        "ss://YWVzLTI1Ni1nY206cGFzcw@203.0.113.1:8388/?plugin=obfs#%E5%8F%B0%E6%B9%BE%2002",
+    )
+    .unwrap();
+    assert_eq!(link.protocol, Protocol::Shadowsocks);
+    assert_eq!((link.server.as_str(), link.port), ("203.0.113.1", 8388));
+    assert_eq!(link.country.unwrap().alpha2, "TW");
+
+    // 整体Base64编码的旧格式
+    let link =
+        parse_link("ss://Y2hhY2hhMjAtaWV0Zi1wb2x5MTMwNTpwd0AxLjIuMy40OjgzODg=#Korea").unwrap();
+    assert_eq!((link.server.as_str(), link.port), ("1.2.3.4", 8388));
+    assert_eq!(link.country.unwrap().alpha2, "KR");
+}
+
+#[test]
+fn test_vmess() {
+    let link = parse_link(
+        "vmess://eyJ2IjogIjIiLCAicHMiOiAi8J+HuPCfh6wg5paw5Yqg5Z2hIDAxIiwgImFkZCI6ICJzZy5leGFtcGxlLmNvbSIsICJwb3J0IjogIjQ0MyIsICJpZCI6ICJ4IiwgImFpZCI6ICIwIiwgIm5ldCI6ICJ3cyJ9",
+    )
+    .unwrap();
+    assert_eq!(link.protocol, Protocol::Vmess);
+    assert_eq!(link.name, "🇸🇬 新加坡 01");
+    assert_eq!((link.server.as_str(), link.port), ("sg.example.com", 443));
+    assert_eq!(link.country.unwrap().alpha2, "SG");
+}
+
+#[test]
+fn test_host_fallback() {
+    // 名称中没有国家，使用服务器主机名；数字端口也能解析
+    let link = parse_link(
+        "vmess://eyJ2IjogIjIiLCAicHMiOiAi6IqC54K5IDEiLCAiYWRkIjogIm5vZGUuZXhhbXBsZS5kZSIsICJwb3J0IjogODQ0MywgImlkIjogIngifQ",
+    )
+    .unwrap();
+    assert_eq!(link.port, 8443);
+    assert_eq!(link.country.unwrap().alpha2, "DE");
+
+    let link = parse_link("trojan://pw@198.51.100.7:443#%E8%8A%82%E7%82%B9%201").unwrap();
+    assert!(matches!(link.country, Err(ParseError::NotFound { .. })));
+}
+
+#[test]
+fn test_invalid_links() {
+    for uri in [
+        "",
+        "https://example.com",
+        "trojan://pw@example.com",
+        "trojan://pw@example.com:99999",
+        "vmess://not-base64!",
+        "ss://bm90LWEtbGluaw",
+    ] {
+        assert!(
+            matches!(parse_link(uri), Err(ParseError::InvalidInput { .. })),
+            "{}",
+            uri
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use location_rs::{parse_country_code, parse_detailed, Configuration, Locale, MatchStage};
+
+fn country_of(text: &str) -> String {
+    parse_country_code(text).unwrap().alpha2
+}
+
+#[test]
+fn test_localized_names_data() {
+    let configuration = Configuration::load().unwrap();
+    let jp = configuration.get_countries().iter().find(|c| c.alpha2 == "JP").unwrap();
+
+    assert_eq!(jp.names_in(&Locale::JA), ["日本"]);
+    assert_eq!(jp.names_in(&Locale::KO), ["일본"]);
+    assert_eq!(jp.names_in(&Locale::RU), ["Япония"]);
+    assert!(jp.names_in(&Locale::new("fr")).is_empty());
+}
+
+#[test]
+fn test_japanese_names() {
+    let result = parse_detailed("アメリカ 01").unwrap();
+    assert_eq!(result.country.alpha2, "US");
+    assert_eq!(result.stage, MatchStage::Localized);
+
+    assert_eq!(country_of("【香港】高速"), "HK");
+    assert_eq!(country_of("シンガポール-02"), "SG");
+}
+
+#[test]
+fn test_korean_names() {
+    assert_eq!(country_of("미국 서버"), "US");
+    assert_eq!(country_of("홍콩01"), "HK");
+    assert_eq!(country_of("인도네시아"), "ID");
+}
+
+#[test]
+fn test_russian_names() {
+    assert_eq!(country_of("США 1"), "US");
+    assert_eq!(country_of("Япония"), "JP");
+    assert_eq!(country_of("германия"), "DE");
+}
+
+#[test]
+fn test_script_boundaries() {
+    // 片假名名称不能是更长单词的一部分（"タイムサーバー" 不是泰国）
+    assert!(parse_country_code("タイムサーバー").is_err());
+    assert_eq!(country_of("タイ・バンコク"), "TH");
+}
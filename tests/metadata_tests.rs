@@ -0,0 +1,45 @@
+#![cfg(feature = "metadata")]
+
+use location_rs::metadata::{all_metadata, metadata_of};
+use location_rs::{parse_country_code, Configuration};
+
+#[test]
+fn test_metadata_covers_all_countries() {
+    let configuration = Configuration::load().unwrap();
+    let metadata = all_metadata().unwrap();
+
+    assert_eq!(metadata.len(), configuration.get_countries().len());
+    for country in configuration.get_countries() {
+        assert!(
+            country.metadata().is_some(),
+            "{} 缺少元数据",
+            country.alpha2
+        );
+    }
+}
+
+#[test]
+fn test_metadata_fields() {
+    let cn = metadata_of("CN").unwrap();
+    assert_eq!(cn.capital.as_deref(), Some("Beijing"));
+    assert_eq!(cn.currencies, ["CNY"]);
+    assert_eq!(cn.tlds, [".cn"]);
+    assert_eq!(cn.languages, ["zh"]);
+    assert_eq!(cn.timezones[0], "Asia/Shanghai");
+
+    let us = metadata_of("us").unwrap();
+    assert!(us.timezones.contains(&"America/New_York".to_string()));
+    assert!(metadata_of("XX").is_none());
+}
+
+#[test]
+fn test_country_info_accessors() {
+    let country = parse_country_code("香港 01").unwrap();
+    assert_eq!(country.currencies(), ["HKD"]);
+    assert_eq!(country.tlds(), [".hk"]);
+    assert_eq!(country.timezones(), ["Asia/Hong_Kong"]);
+
+    let country = parse_country_code("瑞士").unwrap();
+    assert_eq!(country.capital(), Some("Bern"));
+    assert_eq!(country.languages(), ["de", "fr", "it", "rm"]);
+}
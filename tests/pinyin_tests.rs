@@ -0,0 +1,54 @@
+use location_rs::{parse_country_code, parse_detailed, MatchStage};
+
+fn country_of(text: &str) -> String {
+    parse_country_code(text).unwrap().alpha2
+}
+
+#[test]
+fn test_pinyin_names() {
+    for (text, expected) in [
+        ("Xianggang 01", "HK"),
+        ("Riben", "JP"),
+        ("Meiguo-VIP", "US"),
+        ("Xinjiapo", "SG"),
+        ("Hanguo", "KR"),
+    ] {
+        assert_eq!(country_of(text), expected, "{}", text);
+    }
+
+    let result = parse_detailed("Riben").unwrap();
+    assert_eq!(result.stage, MatchStage::Pinyin);
+}
+
+#[test]
+fn test_pinyin_tones_and_spaces() {
+    assert_eq!(country_of("Xiāng Gǎng"), "HK");
+    assert_eq!(country_of("xiang gang 02"), "HK");
+    assert_eq!(country_of("Fēilǜbīn"), "PH");
+    assert_eq!(country_of("Feilubin"), "PH");
+
+    let result = parse_detailed("Ri Ben Tokyo").unwrap();
+    assert_eq!(result.country.alpha2, "JP");
+    assert_eq!(result.span, 0..6);
+}
+
+#[test]
+fn test_pinyin_word_boundaries() {
+    // 拼音必须是完整单词
+    assert!(parse_detailed("Meiguoxyz").map_or(true, |m| m.stage != MatchStage::Pinyin));
+    assert!(parse_detailed("xRiben").map_or(true, |m| m.stage != MatchStage::Pinyin));
+
+    // 空格必须落在音节之间
+    assert!(parse_detailed("Xian ggang").map_or(true, |m| m.stage != MatchStage::Pinyin));
+}
+
+#[test]
+fn test_pinyin_false_positives() {
+    // 繁体名称 "盧安達" 不生成拼音，"Angela"、"Aman" 与英文人名相同
+    for text in ["Luanda", "Angela", "Aman Resort"] {
+        let result = parse_detailed(text);
+        assert!(result.map_or(true, |m| m.stage != MatchStage::Pinyin), "{}", text);
+    }
+    assert!(parse_country_code("Angela").is_err());
+    assert_eq!(country_of("Luwangda"), "RW");
+}
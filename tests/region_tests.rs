@@ -0,0 +1,75 @@
+use location_rs::{parse_location, Configuration, Location, Parser};
+
+fn region_code(text: &str) -> String {
+    match parse_location(text) {
+        Ok(Location::Region(region)) => region.code,
+        other => panic!("{} 未解析为地区: {:?}", text, other),
+    }
+}
+
+fn country_code(text: &str) -> String {
+    match parse_location(text) {
+        Ok(Location::Country(m)) => m.country.alpha2,
+        other => panic!("{} 未解析为国家: {:?}", text, other),
+    }
+}
+
+#[test]
+fn test_country_region_metadata() {
+    let configuration = Configuration::load().unwrap();
+    let jp = configuration.get_countries().iter().find(|c| c.alpha2 == "JP").unwrap();
+    assert_eq!(jp.region.as_deref(), Some("142"));
+    assert_eq!(jp.sub_region.as_deref(), Some("030"));
+
+    let br = configuration.get_countries().iter().find(|c| c.alpha2 == "BR").unwrap();
+    assert_eq!(br.intermediate_region.as_deref(), Some("005"));
+}
+
+#[test]
+fn test_countries_in_region() {
+    let configuration = Configuration::load().unwrap();
+
+    let southeast_asia: Vec<_> = configuration
+        .countries_in_region("东南亚")
+        .iter()
+        .map(|c| c.alpha2.as_str())
+        .collect();
+    assert!(southeast_asia.contains(&"SG"));
+    assert!(southeast_asia.contains(&"VN"));
+    assert!(!southeast_asia.contains(&"JP"));
+
+    // 上级地区包含下级地区的国家
+    let europe = configuration.countries_in_region("150");
+    assert!(europe.iter().any(|c| c.alpha2 == "DE"));
+    assert!(europe.iter().any(|c| c.alpha2 == "GB"));
+
+    // 非M49分组
+    let middle_east = configuration.countries_in_region("Middle East");
+    assert!(middle_east.iter().any(|c| c.alpha2 == "AE"));
+    assert!(middle_east.iter().any(|c| c.alpha2 == "EG"));
+
+    assert!(configuration.countries_in_region("火星").is_empty());
+}
+
+#[test]
+fn test_parse_region() {
+    assert_eq!(region_code("欧洲节点"), "150");
+    assert_eq!(region_code("亚洲 01"), "142");
+    assert_eq!(region_code("North America"), "021");
+    assert_eq!(region_code("中东专线"), "middle-east");
+    assert_eq!(region_code("东南亚-02"), "035");
+    assert_eq!(region_code("Southeast Asia"), "035");
+    assert_eq!(region_code("歐洲節點"), "150");
+}
+
+#[test]
+fn test_country_takes_precedence() {
+    assert_eq!(country_code("欧洲-德国"), "DE");
+    assert_eq!(country_code("亚洲 JP 01"), "JP");
+    assert_eq!(country_code("South Africa"), "ZA");
+    assert_eq!(country_code("美国节点"), "US");
+    assert!(parse_location("普通标题").is_err());
+
+    let parser = Parser::new();
+    assert!(matches!(parser.parse_location("非洲"), Ok(Location::Region(r)) if r.code == "002"));
+}
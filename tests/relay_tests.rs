@@ -0,0 +1,75 @@
+use location_rs::relay::parse_relay;
+use location_rs::Parser;
+
+fn path(text: &str) -> (Option<String>, String) {
+    let result = parse_relay(text).unwrap();
+    let entry = result
+        .entry
+        .as_ref()
+        .map(|entry| entry.country.alpha2.clone());
+    (entry, result.country().alpha2.clone())
+}
+
+fn relay(entry: &str, exit: &str) -> (Option<String>, String) {
+    (Some(entry.to_string()), exit.to_string())
+}
+
+#[test]
+fn test_separators() {
+    assert_eq!(path("香港→美国"), relay("HK", "US"));
+    assert_eq!(path("HK->JP Relay"), relay("HK", "JP"));
+    assert_eq!(path("HK-US 中转"), relay("HK", "US"));
+    assert_eq!(path("香港中转美国 01"), relay("HK", "US"));
+    assert_eq!(path("台湾转日本"), relay("TW", "JP"));
+}
+
+#[test]
+fn test_compact_lines() {
+    let result = parse_relay("沪日专线").unwrap();
+    let entry = result.entry.as_ref().unwrap();
+    assert_eq!(entry.country.alpha2, "CN");
+    assert_eq!(entry.subdivision.as_ref().unwrap().code, "CN-SH");
+    assert_eq!(entry.span, 0..3);
+    assert_eq!(result.country().alpha2, "JP");
+    assert_eq!(result.exit.span, 3..6);
+
+    let result = parse_relay("广港IPLC-日本").unwrap();
+    assert_eq!(result.country().alpha2, "JP");
+    assert_eq!(result.exit.span, 11..17);
+    assert_eq!(result.entry.unwrap().city.unwrap().name_en, "Guangzhou");
+
+    assert_eq!(path("深港专线 01"), relay("CN", "HK"));
+}
+
+#[test]
+fn test_not_relay() {
+    for (text, exit) in [
+        ("香港 01", "HK"),
+        ("JP-02 IPLC", "JP"),
+        ("新加坡 IPLC", "SG"),
+        ("日本专线", "JP"),
+        ("US-CA 01", "US"),
+    ] {
+        let result = parse_relay(text).unwrap();
+        assert!(!result.is_relay(), "{}", text);
+        assert_eq!(result.country().alpha2, exit, "{}", text);
+    }
+
+    assert!(parse_relay("官方网站").is_err());
+}
+
+#[test]
+fn test_subdivision_code_not_split() {
+    let result = parse_relay("HK → US-CA").unwrap();
+    assert_eq!(result.country().alpha2, "US");
+    assert_eq!(result.entry.unwrap().country.alpha2, "HK");
+    assert_eq!(result.exit.subdivision.unwrap().code, "US-CA");
+}
+
+#[test]
+fn test_parser_method() {
+    let parser = Parser::new();
+    let result = parser.parse_relay("日本 → 美国 洛杉矶").unwrap();
+    assert_eq!(result.entry.unwrap().country.alpha2, "JP");
+    assert_eq!(result.exit.city.unwrap().name_en, "Los Angeles");
+}
@@ -0,0 +1,118 @@
+use location_rs::rename::rename;
+use location_rs::{parse_country_code, parse_detailed, ParseError, RenameTemplate};
+
+#[test]
+fn test_flag() {
+    assert_eq!(parse_country_code("香港").unwrap().flag(), "🇭🇰");
+    assert_eq!(parse_country_code("美国").unwrap().flag(), "🇺🇸");
+}
+
+#[test]
+fn test_render_fields() {
+    let result = parse_detailed("加州 圣何塞 01").unwrap();
+
+    let template: RenameTemplate =
+        "{name_zh_cn}·{subdivision_zh_cn}·{city_zh_cn} [{alpha3}/{subdivision}] {{{index:03}}}"
+            .parse()
+            .unwrap();
+    assert_eq!(
+        template.render(&result, 7, "加州 圣何塞 01"),
+        "美国·加利福尼亚州·圣何塞 [USA/US-CA] {007}"
+    );
+
+    let template = RenameTemplate::parse("{alpha2} | {original}").unwrap();
+    assert_eq!(
+        template.render(&result, 1, "加州 圣何塞 01"),
+        "US | 加州 圣何塞 01"
+    );
+
+    // 只去掉空占位符留下的空白，值和模板中的空白保持不变
+    let result = parse_detailed("香港  A   B").unwrap();
+    let template = RenameTemplate::parse("{original}").unwrap();
+    assert_eq!(template.render(&result, 1, "香港  A   B"), "香港  A   B");
+    let template = RenameTemplate::parse("{city} {alpha2}:  {original}").unwrap();
+    assert_eq!(template.render(&result, 1, "香港  A   B"), "HK:  香港  A   B");
+}
+
+#[test]
+fn test_attribute_fields() {
+    let titles = ["香港 IPLC 0.5x", "洛杉矶 CN2 GIA 2倍", "香港 02"];
+    let names = rename(&titles, "{flag} {alpha2}-{city} {index:02} {multiplier}").unwrap();
+    assert_eq!(names, ["🇭🇰 HK 01 0.5x", "🇺🇸 US-Los Angeles 01 2x", "🇭🇰 HK 02"]);
+
+    let template = RenameTemplate::parse("{alpha2} [{line_type}|{carrier}] {bandwidth} {streaming}").unwrap();
+    let title = "美国 CN2 GIA 1Gbps Netflix 解锁";
+    let result = parse_detailed(title).unwrap();
+    assert_eq!(
+        template.render(&result, 1, title),
+        "US [CN2 GIA|China Telecom] 1Gbps Netflix/Unlock"
+    );
+
+    // 原名称中没有属性时占位符为空
+    let template = RenameTemplate::parse("{alpha2}-{carrier} {bandwidth}").unwrap();
+    assert_eq!(template.render(&result, 1, "美国"), "US");
+}
+
+#[test]
+fn test_rename_sequence_and_unmatched() {
+    let titles = [
+        "香港 01",
+        "日本 东京",
+        "HK 高速",
+        "官方网站",
+        "日本 大阪",
+        "香港 IPLC",
+    ];
+    let names = rename(&titles, "{flag} {alpha2}-{city} {index:02}").unwrap();
+    assert_eq!(
+        names,
+        [
+            "🇭🇰 HK 01",
+            "🇯🇵 JP-Tokyo 01",
+            "🇭🇰 HK 02",
+            "官方网站",
+            "🇯🇵 JP-Osaka 02",
+            "🇭🇰 HK 03"
+        ]
+    );
+}
+
+#[test]
+fn test_rename_unique() {
+    let names = rename(&["香港 A", "香港 B", "香港 C", "HK 2"], "{name_en}").unwrap();
+    assert_eq!(names, ["Hong Kong", "Hong Kong 2", "Hong Kong 3", "Hong Kong 4"]);
+
+    // 后缀不与已有名称冲突
+    let names = rename(&["日本 A", "美国 B", "节点 2"], "节点").unwrap();
+    assert_eq!(names, ["节点", "节点 3", "节点 2"]);
+}
+
+#[test]
+fn test_invalid_templates() {
+    for template in [
+        "{country}",
+        "{alpha2",
+        "alpha2}",
+        "{alpha2:02}",
+        "{index:x}",
+    ] {
+        assert!(
+            matches!(
+                RenameTemplate::parse(template),
+                Err(ParseError::ConfigError { .. })
+            ),
+            "{}",
+            template
+        );
+    }
+}
+
+#[test]
+fn test_empty_placeholders() {
+    let result = parse_detailed("日本 01").unwrap();
+
+    let render = |template: &str| RenameTemplate::parse(template).unwrap().render(&result, 1, "日本 01");
+    assert_eq!(render("{city}-{alpha2}"), "JP");
+    assert_eq!(render("{alpha2} - {city} - {index}"), "JP 1");
+    assert_eq!(render("[{alpha2}|{subdivision}]"), "[JP]");
+}
@@ -0,0 +1,88 @@
+use location_rs::{
+    parse_country_code, parse_detailed, Configuration, MatchStage, Parser, ParserConfig,
+    ReservedKind,
+};
+
+#[test]
+fn test_exceptionally_reserved_codes() {
+    let result = parse_detailed("EU 01").unwrap();
+    assert_eq!(result.country.alpha2, "EU");
+    assert_eq!(result.stage, MatchStage::Reserved);
+    let reserved = result.reserved.unwrap();
+    assert_eq!(reserved.kind, ReservedKind::ExceptionallyReserved);
+    assert!(reserved.is_pseudo_entity());
+
+    assert_eq!(parse_country_code("SU-02").unwrap().alpha2, "RU");
+
+    // 保留代码先于alpha-2代码匹配，"Vip" 中的 "VI" 不是美属维尔京群岛
+    assert_eq!(parse_country_code("Vip EU").unwrap().alpha2, "EU");
+}
+
+#[test]
+fn test_uk_alias() {
+    let names = [
+        "UK-1",
+        "Vip UK",
+        "Netflix UK",
+        "uk node",
+        "Premium UK 01",
+        "Standard UK",
+        "Basic UK",
+        "Trial UK 01",
+        "Game UK",
+    ];
+    for name in names {
+        assert_eq!(parse_country_code(name).unwrap().alpha2, "GB", "{:?}", name);
+    }
+}
+
+#[test]
+fn test_historical_codes() {
+    let parser = Parser::with_config(ParserConfig {
+        historical_reserved: true,
+        ..Default::default()
+    });
+
+    let result = parser.parse_detailed("[YU] 01").unwrap();
+    assert_eq!(result.country.alpha2, "RS");
+    let reserved = result.reserved.unwrap();
+    assert_eq!(reserved.kind, ReservedKind::TransitionallyReserved);
+    assert_eq!(reserved.successors, ["RS", "ME"]);
+
+    assert_eq!(parser.parse("#TP").unwrap().alpha2, "TL");
+    assert_eq!(parser.parse("AN 01").unwrap().alpha2, "CW");
+    assert_eq!(parser.parse("SCG").unwrap().alpha2, "RS");
+    assert_eq!(parser.parse("DD 01").unwrap().alpha2, "DE");
+}
+
+#[test]
+fn test_historical_codes_are_opt_in() {
+    for name in ["[YU] 01", "CS2 游戏加速", "CS:GO 专线", "TP-Link", "DD 01"] {
+        assert!(parse_country_code(name).is_err(), "{:?}", name);
+    }
+}
+
+#[test]
+fn test_reserved_codes_require_uppercase() {
+    let parser = Parser::with_config(ParserConfig {
+        historical_reserved: true,
+        ..Default::default()
+    });
+    for name in ["an 01", "Su 01", "Yu 01", "eu 01"] {
+        assert!(parser.parse(name).is_err(), "{:?}", name);
+    }
+}
+
+#[test]
+fn test_reserved_codes_require_word_boundary() {
+    assert!(parse_country_code("Euro").is_err());
+    assert!(parse_country_code("Anycast").is_err());
+    assert_eq!(parse_country_code("UKRAINE").unwrap().alpha2, "UA");
+}
+
+#[test]
+fn test_find_reserved_code() {
+    let configuration = Configuration::load().unwrap();
+    assert_eq!(configuration.find_reserved_code("yug").unwrap().code, "YU");
+    assert!(configuration.find_reserved_code("US").is_none());
+}
@@ -0,0 +1,69 @@
+use location_rs::{Configuration, ParseError, SchemaVersion, CURRENT_SCHEMA_VERSION};
+
+fn countries_json(version: &str) -> String {
+    format!(
+        r#"{{
+            "version": "{}",
+            "countries": [
+                {{
+                    "alpha2": "US",
+                    "alpha3": "USA",
+                    "name_en": "United States",
+                    "name_zh_cn": "美国",
+                    "name_zh_tw": "美國",
+                    "abbreviations": ["老美"]
+                }}
+            ]
+        }}"#,
+        version
+    )
+}
+
+#[test]
+fn test_embedded_schema_version() {
+    let configuration = Configuration::load().unwrap();
+    assert_eq!(configuration.schema_version().unwrap(), CURRENT_SCHEMA_VERSION);
+
+    let us = configuration.get_countries().iter().find(|c| c.alpha2 == "US").unwrap();
+    assert_eq!(us.numeric.as_deref(), Some("840"));
+}
+
+#[test]
+fn test_migrate_older_schema() {
+    let configuration = Configuration::from_countries_json(&countries_json("1.1")).unwrap();
+    assert_eq!(configuration.schema_version().unwrap(), CURRENT_SCHEMA_VERSION);
+
+    // 新增字段使用内置数据填充，原有字段保持不变
+    let us = &configuration.get_countries()[0];
+    assert_eq!(us.numeric.as_deref(), Some("840"));
+    assert_eq!(us.calling_codes, vec!["1"]);
+    assert_eq!(us.sovereign, None);
+    assert_eq!(us.abbreviations, vec!["老美"]);
+}
+
+#[test]
+fn test_current_schema_optional_fields() {
+    // 当前版本的数据也可以省略电话区号和其他语言的名称
+    let json = countries_json(&CURRENT_SCHEMA_VERSION.to_string());
+    let configuration = Configuration::from_countries_json(&json).unwrap();
+    let us = &configuration.get_countries()[0];
+    assert!(us.calling_codes.is_empty());
+    assert!(us.names.is_empty());
+}
+
+#[test]
+fn test_reject_unsupported_schema() {
+    let newer = SchemaVersion::new(CURRENT_SCHEMA_VERSION.major, CURRENT_SCHEMA_VERSION.minor + 1);
+    for version in [newer.to_string(), "2.0".to_string(), "1.0".to_string(), "latest".to_string()] {
+        let result = Configuration::from_countries_json(&countries_json(&version));
+        assert!(matches!(result, Err(ParseError::ConfigError { .. })), "{}", version);
+    }
+}
+
+#[test]
+fn test_schema_version_parse() {
+    assert_eq!(SchemaVersion::parse("1.2").unwrap(), SchemaVersion::new(1, 2));
+    assert_eq!(SchemaVersion::new(1, 10).to_string(), "1.10");
+    assert!(SchemaVersion::new(1, 10) > SchemaVersion::new(1, 2));
+    assert!(SchemaVersion::parse("1").is_err());
+}
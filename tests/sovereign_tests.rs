@@ -0,0 +1,61 @@
+use location_rs::{parse_detailed, Configuration, Parser, ParserConfig};
+
+fn rollup_parser() -> Parser {
+    let config = ParserConfig {
+        resolve_sovereign: true,
+        ..Default::default()
+    };
+    Parser::with_config(config)
+}
+
+#[test]
+fn test_sovereign_field() {
+    let configuration = Configuration::load().unwrap();
+    let sovereign_of = |alpha2: &str| {
+        let country = configuration
+            .get_countries()
+            .iter()
+            .find(|c| c.alpha2 == alpha2)
+            .unwrap();
+        country.sovereign.clone()
+    };
+
+    assert_eq!(sovereign_of("HK").as_deref(), Some("CN"));
+    assert_eq!(sovereign_of("PR").as_deref(), Some("US"));
+    assert_eq!(sovereign_of("RE").as_deref(), Some("FR"));
+    assert_eq!(sovereign_of("JP"), None);
+}
+
+#[test]
+fn test_territories_of() {
+    let configuration = Configuration::load().unwrap();
+
+    let territories: Vec<&str> = configuration
+        .territories_of("usa")
+        .iter()
+        .map(|c| c.alpha2.as_str())
+        .collect();
+    assert!(territories.contains(&"PR") && territories.contains(&"GU"));
+    assert!(configuration.territories_of("JP").is_empty());
+    assert!(configuration.territories_of("XX").is_empty());
+}
+
+#[test]
+fn test_territories_kept_distinct_by_default() {
+    let result = parse_detailed("香港 01").unwrap();
+    assert_eq!(result.country.alpha2, "HK");
+    assert!(result.territory.is_none());
+}
+
+#[test]
+fn test_resolve_sovereign() {
+    let parser = rollup_parser();
+
+    let result = parser.parse_detailed("香港 01").unwrap();
+    assert_eq!(result.country.alpha2, "CN");
+    assert_eq!(result.territory.unwrap().alpha2, "HK");
+
+    assert_eq!(parser.parse("Greenland").unwrap().alpha2, "DK");
+    assert_eq!(parser.parse("日本").unwrap().alpha2, "JP");
+    assert_eq!(parser.parse_host("node.gu").unwrap().alpha2, "US");
+}
@@ -0,0 +1,50 @@
+use location_rs::{parse_country_code, parse_detailed, Configuration, MatchStage};
+
+fn subdivision_of(text: &str) -> Option<String> {
+    parse_detailed(text).unwrap().subdivision.map(|s| s.code)
+}
+
+#[test]
+fn test_subdivision_code() {
+    let result = parse_detailed("US-加州-01").unwrap();
+    assert_eq!(result.country.alpha2, "US");
+    assert_eq!(result.subdivision.unwrap().code, "US-CA");
+
+    assert_eq!(subdivision_of("DE-HE 节点").as_deref(), Some("DE-HE"));
+}
+
+#[test]
+fn test_subdivision_infers_country() {
+    let result = parse_detailed("加州节点").unwrap();
+    assert_eq!(result.country.alpha2, "US");
+    assert_eq!(result.stage, MatchStage::Subdivision);
+    assert_eq!(result.subdivision.unwrap().code, "US-CA");
+
+    assert_eq!(parse_country_code("California 01").unwrap().alpha2, "US");
+    assert_eq!(parse_country_code("新墨西哥州").unwrap().alpha2, "US");
+}
+
+#[test]
+fn test_subdivision_with_country() {
+    let result = parse_detailed("日本大阪").unwrap();
+    assert_eq!(result.country.alpha2, "JP");
+    assert_eq!(result.subdivision.unwrap().code, "JP-27");
+
+    assert_eq!(subdivision_of("@CN-北京-电信").as_deref(), Some("CN-BJ"));
+}
+
+#[test]
+fn test_numeric_subdivision_code_ignored() {
+    // 纯数字的行政区代码与节点编号无法区分
+    let result = parse_detailed("JP-13").unwrap();
+    assert_eq!(result.country.alpha2, "JP");
+    assert!(result.subdivision.is_none());
+}
+
+#[test]
+fn test_subdivisions_of() {
+    let configuration = Configuration::load().unwrap();
+    let states = configuration.subdivisions_of("us");
+    assert!(states.iter().any(|s| s.code == "US-CA"));
+    assert!(states.iter().all(|s| s.alpha2() == "US"));
+}
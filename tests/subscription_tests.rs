@@ -0,0 +1,98 @@
+#![cfg(feature = "subscription")]
+
+use location_rs::subscription::{decode, SubscriptionFormat};
+use location_rs::ParseError;
+
+fn alpha2s(nodes: &[location_rs::subscription::Node]) -> Vec<&str> {
+    nodes
+        .iter()
+        .map(|node| node.country.as_ref().map_or("", |c| c.alpha2.as_str()))
+        .collect()
+}
+
+#[test]
+fn test_base64_links() {
+    // URL安全字母表、无填充，包含一个不支持的ssr链接
+    let body = b"dHJvamFuOi8vcHdAaGsuZXhhbXBsZS5jb206NDQzI0hLJTIwMDEKdmxlc3M6Ly9pZEBqcC5leGFtcGxlLmNvbTo0NDMjJUU2JTk3JUE1JUU2JTlDJUFDCnNzcjovL2FiYwo";
+    let subscription = decode(body).unwrap();
+
+    assert_eq!(subscription.format, SubscriptionFormat::Base64);
+    assert_eq!(alpha2s(&subscription.nodes), ["HK", "JP"]);
+    assert_eq!(subscription.nodes[1].protocol, "vless");
+    assert_eq!(subscription.nodes[1].port, Some(443));
+    assert!(matches!(
+        subscription.errors[..],
+        [ParseError::InvalidInput { .. }]
+    ));
+}
+
+#[test]
+fn test_plain_links() {
+    let body = "\u{feff}trojan://pw@a.example.com:443#%E7%BE%8E%E5%9B%BD\r\n\r\nhy2://pw@node.example.sg:8443#%E8%8A%82%E7%82%B9\n";
+    let subscription = decode(body.as_bytes()).unwrap();
+
+    assert_eq!(subscription.format, SubscriptionFormat::Links);
+    assert_eq!(alpha2s(&subscription.nodes), ["US", "SG"]);
+}
+
+#[test]
+fn test_clash() {
+    let body = r#"
+proxy-providers:
+  provider:
+    url: https://example.com/sub
+proxies:
+  - { name: "🇯🇵 东京 01", type: vmess, server: jp.example.com, port: 443 }
+  - { name: "节点 2", type: trojan, server: node.example.de, port: 8443 }
+"#;
+    let subscription = decode(body.as_bytes()).unwrap();
+
+    assert_eq!(subscription.format, SubscriptionFormat::Clash);
+    assert_eq!(alpha2s(&subscription.nodes), ["JP", "DE"]);
+    assert_eq!(subscription.nodes[1].protocol, "trojan");
+    assert_eq!(subscription.nodes[1].port, Some(8443));
+}
+
+#[test]
+fn test_clash_invalid_proxy() {
+    let body = r#"
+proxies:
+  - { name: "🇯🇵 东京 01", type: vmess, server: jp.example.com, port: 443 }
+  - { type: ss, server: nameless.example.com, port: 443 }
+  - "broken"
+  - { name: "新加坡 02", type: trojan, server: sg.example.com, port: 443 }
+"#;
+    let subscription = decode(body.as_bytes()).unwrap();
+
+    assert_eq!(subscription.format, SubscriptionFormat::Clash);
+    assert_eq!(alpha2s(&subscription.nodes), ["JP", "SG"]);
+    assert_eq!(subscription.errors.len(), 2);
+}
+
+#[test]
+fn test_sing_box() {
+    let body = r#"{
+        "outbounds": [
+            { "type": "selector", "tag": "proxy", "outbounds": ["新加坡 01"] },
+            { "type": "shadowsocks", "tag": "新加坡 01", "server": "sg.example.com", "server_port": 8388 },
+            { "type": "hysteria2", "tag": "韩国 02", "server": "kr.example.com", "server_port": 443 },
+            { "type": "direct", "tag": "direct" }
+        ]
+    }"#;
+    let subscription = decode(body.as_bytes()).unwrap();
+
+    assert_eq!(subscription.format, SubscriptionFormat::SingBox);
+    assert_eq!(alpha2s(&subscription.nodes), ["SG", "KR"]);
+    assert_eq!(subscription.nodes[0].protocol, "ss");
+}
+
+#[test]
+fn test_unrecognized() {
+    for body in [&b""[..], b"   ", b"hello world", b"\xff\xfe", b"port: 7890"] {
+        assert!(
+            matches!(decode(body), Err(ParseError::ConfigError { .. })),
+            "{:?}",
+            body
+        );
+    }
+}
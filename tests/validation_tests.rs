@@ -0,0 +1,49 @@
+use location_rs::{Configuration, ValidationIssueKind};
+
+#[test]
+fn test_embedded_dataset_is_valid() {
+    let report = Configuration::load().unwrap().validate();
+    assert!(report.is_valid(), "内置数据集存在错误: {:#?}", report.errors);
+
+    // "UK" 按完整单词匹配，不会包含在 "Ukraine" 中
+    assert!(!report
+        .issues_of(ValidationIssueKind::AliasShadowsName)
+        .any(|issue| issue.alpha2 == "GB" && issue.other.as_deref() == Some("UA")));
+}
+
+#[test]
+fn test_invalid_dataset() {
+    let json = r#"{
+        "version": "1.1",
+        "countries": [
+            {
+                "alpha2": "AA",
+                "alpha3": "AAA",
+                "name_en": "Alpha",
+                "name_zh_cn": "阿尔法",
+                "name_zh_tw": "",
+                "abbreviations": ["阿尔法", "AL", "布拉"]
+            },
+            {
+                "alpha2": "bb",
+                "alpha3": "AAA",
+                "name_en": "Bravo",
+                "name_zh_cn": "布拉沃",
+                "name_zh_tw": "布拉沃",
+                "abbreviations": ["al", "阿尔法", "拉沃"]
+            }
+        ]
+    }"#;
+
+    let report = Configuration::from_countries_json(json).unwrap().validate();
+    assert!(!report.is_valid());
+
+    let kinds = |kind| report.issues_of(kind).count();
+    assert_eq!(kinds(ValidationIssueKind::MalformedCode), 1);
+    assert_eq!(kinds(ValidationIssueKind::DuplicateCode), 1);
+    assert_eq!(kinds(ValidationIssueKind::DuplicateAlias), 2);
+    assert_eq!(kinds(ValidationIssueKind::AliasConflictsWithName), 1);
+    assert_eq!(kinds(ValidationIssueKind::AliasDuplicatesName), 1);
+    assert_eq!(kinds(ValidationIssueKind::MissingTraditionalName), 1);
+    assert_eq!(kinds(ValidationIssueKind::AliasShadowsName), 1);
+}